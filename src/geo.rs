@@ -2,9 +2,22 @@ mod point;
 mod vector;
 mod matrix3d;
 mod line;
+mod ray;
+mod infinite_line;
 mod arc;
+mod circle;
+mod ellipse;
+mod segment;
 mod plane;
 mod nurbs;
+mod tessellation;
+mod measure;
+mod extension;
+mod distance;
+mod deviation;
+mod frame;
+mod biarc;
+pub mod construct;
 
 use crate::{ BgcError, Tolerance };
 
@@ -13,12 +26,303 @@ pub use vector::Vector as Vector;
 pub use matrix3d::Matrix3d as Matrix3d;
 
 pub use line::Line as Line;
+pub use ray::Ray as Ray;
+pub use infinite_line::InfiniteLine as InfiniteLine;
 pub use arc::Arc as Arc;
+pub use arc::SweepDirection as SweepDirection;
+pub use circle::Circle as Circle;
+pub use ellipse::Ellipse as Ellipse;
+pub use segment::Segment as Segment;
 pub use nurbs::NurbsCurve as NurbsCurve;
 
 pub use plane::Plane as Plane;
 
+pub use tessellation::TessellationOptions as TessellationOptions;
+pub use extension::CurveEnd as CurveEnd;
+pub use extension::SplineExtension as SplineExtension;
+
+/// The closest point on a curve to a query point.
+#[derive(Debug, Clone, Copy)]
+pub struct ClosestPoint {
+    pub point: Point,
+    pub param: f64,
+    pub distance: f64,
+}
+
+/// An intersection point of two lines with the normalized parameters on both lines.
+#[derive(Debug, Clone, Copy)]
+pub struct LineIntersection {
+    pub point: Point,
+    pub param1: f64,
+    pub param2: f64,
+}
+
+/// The closest points between two curves.
+#[derive(Debug, Clone, Copy)]
+pub struct ClosestPoints {
+    pub point1: Point,
+    pub param1: f64,
+    pub point2: Point,
+    pub param2: f64,
+    pub distance: f64,
+}
+
+/// The points where two curves deviate the most from each other.
+#[derive(Debug, Clone, Copy)]
+pub struct CurveDeviation {
+    pub point1: Point,
+    pub param1: f64,
+    pub point2: Point,
+    pub param2: f64,
+    pub distance: f64,
+}
+
+/// The result of projecting a curve onto a plane.
+#[derive(Debug)]
+pub enum ProjectedCurve {
+    Point(Point),
+    Line(Line),
+    Arc(Arc),
+    Ellipse(Ellipse),
+}
+
+/// A point on a curve with its unit tangent.
+#[derive(Debug, Clone, Copy)]
+pub struct CurveSample {
+    pub param: f64,
+    pub point: Point,
+    pub tangent: Vector,
+}
+
+/// A sequence of lines and arcs approximating a curve.
+///
+/// The segments are joined end to end with the same tangent where the curve is smooth.
+#[derive(Debug)]
+pub struct ArcSpline {
+    pub segments: Vec<Segment>,
+    /// The largest distance found from the curve to the segments.
+    pub max_deviation: f64,
+}
+
+/// The Frenet frame and the curvature of a curve at a parameter.
+///
+/// `normal` and `binormal` are `None` where the curve is straight.
+#[derive(Debug, Clone, Copy)]
+pub struct CurveFrame {
+    pub param: f64,
+    pub point: Point,
+    pub tangent: Vector,
+    /// Points to the center of curvature.
+    pub normal: Option<Vector>,
+    pub binormal: Option<Vector>,
+    pub curvature: f64,
+}
+
 pub trait Curve {
+    /// Returns the parameter range `(start, end)` of this curve.
+    fn domain(&self) -> (f64, f64);
+
+    /// Calculates the point at `param`.
+    ///
+    /// If `extends` is false, `param` must lie within the domain. Otherwise the curve is
+    /// evaluated on its natural extension.
+    fn point_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Point, BgcError>;
+
+    /// Calculates the first and second derivatives at `param`.
+    fn derivatives_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<(Vector, Vector), BgcError>;
+
+    /// Calculates the parameter of the projection of `point` onto this curve.
+    ///
+    /// If `extends` is false, the result is limited to the domain.
+    fn param_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError>;
+
+    /// Returns the parameters where this curve may not be smooth, including both ends of
+    /// the domain.
+    fn breakpoints(&self) -> Vec<f64> {
+        let (start, end) = self.domain();
+        vec![start, end]
+    }
+
+    /// Approximates this curve by a polyline within the limits of `options`.
+    ///
+    /// Returns the parameters and points of the vertices.
+    fn tessellate(
+        &self,
+        options: &TessellationOptions,
+        tol: &Tolerance
+    ) -> Result<Vec<(f64, Point)>, BgcError> {
+        tessellation::tessellate(self, options, tol)
+    }
+
+    /// Approximates this curve by lines and arcs within `tol.equal_point()`, fitting a
+    /// biarc to each piece.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::Deivergence)` - no biarc within the tolerance fits a piece of the
+    ///   smallest size
+    fn to_arc_spline(&self, tol: &Tolerance) -> Result<ArcSpline, BgcError> {
+        biarc::approximate(self, tol)
+    }
+
+    /// Calculates the length of this curve between two parameters.
+    fn length_between(
+        &self,
+        start_param: f64,
+        end_param: f64,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        measure::length_between(self, start_param, end_param, tol)
+    }
+
+    /// Calculates the parameter at `length` along this curve from its start.
+    fn param_at_length(&self, length: f64, tol: &Tolerance) -> Result<f64, BgcError> {
+        measure::param_at_length(self, length, tol)
+    }
+
+    /// Divides this curve into `count` pieces of equal length.
+    ///
+    /// Returns `count + 1` samples including both ends.
+    fn divide(&self, count: usize, tol: &Tolerance) -> Result<Vec<CurveSample>, BgcError> {
+        measure::divide(self, count, tol)
+    }
+
+    /// Places samples at every `distance` along this curve, starting at its start.
+    fn measure(&self, distance: f64, tol: &Tolerance) -> Result<Vec<CurveSample>, BgcError> {
+        measure::measure(self, distance, tol)
+    }
+
+    /// Calculates the Frenet frame and the curvature at `param`.
+    fn frame_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<CurveFrame, BgcError> {
+        frame::frame_at_param(self, param, extends, tol)
+    }
+
+    /// Calculates the Frenet frame and the curvature at the projection of input point.
+    ///
+    /// The tangent, normal, binormal and curvature below are queried by parameter only;
+    /// read them from this frame to query by point.
+    fn frame_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<CurveFrame, BgcError> {
+        frame::frame_at_point(self, point, extends, tol)
+    }
+
+    /// Calculates the unit tangent at `param`.
+    fn tangent_at_param(&self, param: f64, extends: bool, tol: &Tolerance) -> Result<Vector, BgcError> {
+        Ok(self.frame_at_param(param, extends, tol)?.tangent)
+    }
+
+    /// Calculates the unit principal normal at `param`.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBeNonZero)` - the curve is straight at `param`
+    fn normal_at_param(&self, param: f64, extends: bool, tol: &Tolerance) -> Result<Vector, BgcError> {
+        self.frame_at_param(param, extends, tol)?.normal.ok_or(BgcError::MustBeNonZero)
+    }
+
+    /// Calculates the unit binormal at `param`.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBeNonZero)` - the curve is straight at `param`
+    fn binormal_at_param(&self, param: f64, extends: bool, tol: &Tolerance) -> Result<Vector, BgcError> {
+        self.frame_at_param(param, extends, tol)?.binormal.ok_or(BgcError::MustBeNonZero)
+    }
+
+    /// Calculates the curvature at `param`.
+    fn curvature_at_param(&self, param: f64, extends: bool, tol: &Tolerance) -> Result<f64, BgcError> {
+        Ok(self.frame_at_param(param, extends, tol)?.curvature)
+    }
+
+    /// Calculates the closest point on this curve to input point.
+    fn project_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<ClosestPoint, BgcError> {
+        let param = self.param_at_point(point, extends, tol)?;
+        let closest = self.point_at_param(param, extends, tol)?;
+
+        Ok(ClosestPoint { point: closest, param, distance: closest.distance_to(point) })
+    }
+
+    /// Calculates the distance from input point to this curve.
+    fn distance_to_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        Ok(self.project_point(point, extends, tol)?.distance)
+    }
+
+    /// Calculates the closest points between this curve and input curve.
+    ///
+    /// `point1` and `param1` are on this curve. The distance is zero where the curves
+    /// intersect.
+    fn closest_points_to(&self, other: &dyn Curve, tol: &Tolerance) -> Result<ClosestPoints, BgcError> {
+        distance::closest_points(self, other, tol)
+    }
+
+    /// Calculates the minimum distance between this curve and input curve.
+    fn distance_to_curve(&self, other: &dyn Curve, tol: &Tolerance) -> Result<f64, BgcError> {
+        Ok(self.closest_points_to(other, tol)?.distance)
+    }
+
+    /// Calculates the Hausdorff distance between this curve and input curve on `samples`
+    /// pieces of equal length of each curve.
+    fn discrete_hausdorff_distance(
+        &self,
+        other: &dyn Curve,
+        samples: usize,
+        tol: &Tolerance
+    ) -> Result<CurveDeviation, BgcError> {
+        deviation::discrete_hausdorff(self, other, samples, tol)
+    }
+
+    /// Calculates the Hausdorff distance between this curve and input curve.
+    ///
+    /// The largest deviations of the samples are refined on the curves.
+    fn hausdorff_distance(&self, other: &dyn Curve, tol: &Tolerance) -> Result<CurveDeviation, BgcError> {
+        deviation::hausdorff(self, other, tol)
+    }
+
+    /// Calculates the discrete Fréchet distance between this curve and input curve on
+    /// `samples` pieces of equal length of each curve.
+    fn discrete_frechet_distance(
+        &self,
+        other: &dyn Curve,
+        samples: usize,
+        tol: &Tolerance
+    ) -> Result<CurveDeviation, BgcError> {
+        deviation::discrete_frechet(self, other, samples, tol)
+    }
+
     fn intersect_with_line(
         &self,
         line: &Line,
@@ -40,3 +344,70 @@ pub trait Curve {
         tol: &Tolerance
     ) -> Result<Vec<Point>, BgcError>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curve_project_point() {
+        let tol = Tolerance::default();
+
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 5.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+        let nurbs = NurbsCurve::new(
+            2,
+            vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 0.0), Point::new(2.0, 0.0, 0.0)],
+            vec![1.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        let curves: Vec<&dyn Curve> = vec![&line, &arc, &nurbs];
+        let point = Point::new(1.0, 6.0, 0.0);
+
+        let results: Vec<ClosestPoint> = curves
+            .iter()
+            .map(|c| c.project_point(&point, false, &tol).unwrap())
+            .collect();
+
+        assert!(results[0].point.is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!((results[0].param - 0.1).abs() < tol.calculation());
+        assert!((results[0].distance - 6.0).abs() < tol.calculation());
+
+        let expected = Point::new(1.0, 6.0, 0.0) - Point::origin();
+        let expected = Point::origin() + expected.normal(&tol) * 5.0;
+        assert!(results[1].point.is_equal_to(&expected, &tol));
+        assert!((results[1].distance - (37.0_f64.sqrt() - 5.0)).abs() < tol.calculation());
+
+        // The apex of the parabola is the closest to the point above it
+        assert!(results[2].point.is_equal_to(&Point::new(1.0, 1.0, 0.0), &tol));
+        assert!((results[2].param - 0.5).abs() < tol.calculation());
+        assert!((results[2].distance - 5.0).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn curve_project_point_extends() {
+        let tol = Tolerance::default();
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+        let curve: &dyn Curve = &line;
+
+        let point = Point::new(15.0, 3.0, 0.0);
+
+        let closest = curve.project_point(&point, false, &tol).unwrap();
+        assert!(closest.point.is_equal_to(&line.end_point, &tol));
+        assert!((closest.param - 1.0).abs() < tol.calculation());
+
+        let closest = curve.project_point(&point, true, &tol).unwrap();
+        assert!(closest.point.is_equal_to(&Point::new(15.0, 0.0, 0.0), &tol));
+        assert!((closest.param - 1.5).abs() < tol.calculation());
+        assert!((curve.distance_to_point(&point, true, &tol).unwrap() - 3.0).abs() < tol.calculation());
+    }
+}
@@ -1,6 +1,20 @@
 use super::*;
 use crate::{ math::{self}, BgcError, Tolerance };
 
+/// The direction an arc runs about its normal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SweepDirection {
+    CounterClockwise,
+    Clockwise,
+}
+
+/// An arc from `start_angle` to `end_angle` in the local coordinate system.
+///
+/// The arc runs counterclockwise about x_axis × y_axis when `end_angle` is larger than
+/// `start_angle`, and clockwise otherwise. The angles may lie outside [0, 2π), so the
+/// sweep may cross the x axis.
+///
+/// Methods returning a new arc return a clockwise arc as its counterclockwise equivalent.
 #[derive(Debug)]
 pub struct Arc {
     pub center_point: Point,
@@ -65,8 +79,218 @@ impl Arc {
                   end_angle })
     }
 
+    /// Makes an arc from its center, normal, radius and angles.
+    ///
+    /// The angles are measured counterclockwise about the normal from the x axis chosen
+    /// by the arbitrary axis algorithm of DXF. An end angle below the start angle wraps
+    /// through 2π.
+    pub fn from_center_radius_angles(
+        center: &Point,
+        normal: &Vector,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        if radius < tol.equal_point() {
+            return Err(BgcError::MustBePositive);
+        }
+        if normal.length() < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+
+        let full = std::f64::consts::PI * 2.0;
+        if end_angle - start_angle > full + tol.calculation() {
+            return Err(BgcError::OutOfRange);
+        }
+
+        let sweep = if end_angle - start_angle > full - tol.calculation() {
+            full
+        } else {
+            (end_angle - start_angle).rem_euclid(full)
+        };
+        if sweep < tol.calculation() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let z_axis = normal.normal(tol);
+        let x_axis = Arc::arbitrary_x_axis(&z_axis, tol);
+        let frame = Self { center_point: *center,
+                           x_axis,
+                           y_axis: z_axis.outer_product(&x_axis),
+                           radius,
+                           start_angle: 0.0,
+                           end_angle: full };
+
+        let start_angle = start_angle.rem_euclid(full);
+        Ok(frame.with_angles(start_angle, start_angle + sweep))
+    }
+
+    /// Makes an arc from its center, start point and end point.
+    ///
+    /// The arc runs from the start point to the end point in `direction` about the normal.
+    /// Both points must lie at the same distance from the center on the plane through the
+    /// center perpendicular to the normal.
+    pub fn from_center_start_end(
+        center: &Point,
+        start_point: &Point,
+        end_point: &Point,
+        normal: &Vector,
+        direction: SweepDirection,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        if normal.length() < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+
+        let radius = center.distance_to(start_point);
+        if radius < tol.equal_point() {
+            return Err(BgcError::MustBePositive);
+        }
+        if (center.distance_to(end_point) - radius).abs() > tol.equal_point()
+                || start_point.is_equal_to(end_point, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let z_axis = normal.normal(tol);
+        let x_axis = (start_point - center).normal(tol);
+        if z_axis.inner_product(&x_axis).abs() > tol.equal_vector()
+                || z_axis.inner_product(&(end_point - center)).abs() > tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let y_axis = match direction {
+            SweepDirection::CounterClockwise => z_axis.outer_product(&x_axis),
+            SweepDirection::Clockwise => x_axis.outer_product(&z_axis),
+        };
+
+        let to_end = end_point - center;
+        let local_end = Point::new(to_end.inner_product(&x_axis), to_end.inner_product(&y_axis), 0.0);
+
+        Ok(Self { center_point: *center,
+                  x_axis,
+                  y_axis,
+                  radius,
+                  start_angle: 0.0,
+                  end_angle: Arc::calc_angle_at_local_point(&local_end) })
+    }
+
+    /// Makes an arc from its start point, the tangent at the start point and its end point.
+    pub fn from_start_tangent_end(
+        start_point: &Point,
+        tangent: &Vector,
+        end_point: &Point,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        if tangent.length() < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+        if start_point.is_equal_to(end_point, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let y_axis = tangent.normal(tol);
+        let chord = end_point - start_point;
+        let normal = y_axis.outer_product(&chord);
+        if normal.length() < tol.equal_vector() * chord.length() {
+            // The end point lies on the tangent
+            return Err(BgcError::InvalidInput);
+        }
+
+        // The center lies on the side of the end point, equally distant from both points.
+        // The normal is scaled by hand as it may be shorter than `tol.equal_vector()`.
+        let to_center = normal.outer_product(&y_axis) * (1.0 / normal.length());
+        let radius = chord.inner_product(&chord) / (2.0 * chord.inner_product(&to_center));
+        let center = *start_point + to_center * radius;
+
+        let x_axis = to_center * -1.0;
+        let to_end = end_point - center;
+        let local_end = Point::new(to_end.inner_product(&x_axis), to_end.inner_product(&y_axis), 0.0);
+
+        Ok(Self { center_point: center,
+                  x_axis,
+                  y_axis,
+                  radius,
+                  start_angle: 0.0,
+                  end_angle: Arc::calc_angle_at_local_point(&local_end) })
+    }
+
+    /// Makes an arc between two points from a DXF bulge.
+    ///
+    /// bulge = tan(θ/4), where θ is the included angle <br>
+    /// A positive bulge runs counterclockwise about the normal, a negative one clockwise.
+    pub fn from_bulge(
+        start_point: &Point,
+        end_point: &Point,
+        bulge: f64,
+        normal: &Vector,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        if bulge.abs() < tol.calculation() {
+            return Err(BgcError::MustBeNonZero);
+        }
+        if normal.length() < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+        if start_point.is_equal_to(end_point, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let z_axis = normal.normal(tol);
+        let chord = end_point - start_point;
+        if z_axis.inner_product(&chord).abs() > tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let angle = 4.0 * bulge.abs().atan();
+        let half_chord = chord.length() / 2.0;
+        let radius = half_chord / (angle / 2.0).sin();
+
+        // Signed distance from the middle of the chord to the center, to the left of the chord
+        let height = radius * (angle / 2.0).cos();
+        let left = z_axis.outer_product(&chord).normal(tol);
+        let (center, direction) = if bulge > 0.0 {
+            (start_point.calc_middle_point(end_point) + left * height, SweepDirection::CounterClockwise)
+        } else {
+            (start_point.calc_middle_point(end_point) - left * height, SweepDirection::Clockwise)
+        };
+
+        Self::from_center_start_end(&center, start_point, end_point, &z_axis, direction, tol)
+    }
+
     pub fn length(&self) -> f64 {
-        self.calc_length_at_param(self.end_angle)
+        self.sweep_angle().abs() * self.radius
+    }
+
+    /// Returns the signed angle from the start to the end, negative for a clockwise arc.
+    pub fn sweep_angle(&self) -> f64 {
+        self.end_angle - self.start_angle
+    }
+
+    pub fn is_clockwise(&self) -> bool {
+        self.end_angle < self.start_angle
+    }
+
+    /// Returns the same arc running counterclockwise about its frame.
+    ///
+    /// The y axis of a clockwise arc is reversed and its angles negated. The parameter of
+    /// a clockwise arc as a `Curve` is the angle on this equivalent.
+    pub fn to_counterclockwise(&self) -> Self {
+        if self.is_clockwise() {
+            Self { center_point: self.center_point,
+                   x_axis: self.x_axis,
+                   y_axis: self.y_axis * -1.0,
+                   radius: self.radius,
+                   start_angle: -self.start_angle,
+                   end_angle: -self.end_angle }
+        } else {
+            Self { center_point: self.center_point,
+                   x_axis: self.x_axis,
+                   y_axis: self.y_axis,
+                   radius: self.radius,
+                   start_angle: self.start_angle,
+                   end_angle: self.end_angle }
+        }
     }
 
     pub fn start_point(&self) -> Point {
@@ -84,41 +308,9 @@ impl Arc {
         extends: bool,
         tol: &Tolerance
     ) -> Result<Point, BgcError> {
-        let mut local_point = point.transform(
-            &Matrix3d::transform_to_local(
-                &self.center_point,
-                &self.x_axis,
-                &self.y_axis,
-                tol
-            ),
-            tol
-        )?;
-        local_point.z = 0.0;
-
-        let line = Line::new(Point::origin(), local_point);
-        let on_arc = line.point_at_dist(self.radius, true, tol)?;
-        let angle = Arc::calc_angle_at_local_point(&on_arc);
-
-        if !extends && !self.is_param_in_range(angle, tol) {
-            let to_start = self.start_point().distance_to(point);
-            let to_end = self.end_point().distance_to(point);
+        let param = self.param_at_point(point, extends, tol)?;
 
-            if to_start - to_end <= tol.equal_point() {
-                Ok(self.start_point())
-            } else {
-                Ok(self.end_point())
-            }
-        } else {
-            Ok(on_arc.transform(
-                &Matrix3d::transform_to_world(
-                    &self.center_point,
-                    &self.x_axis,
-                    &self.y_axis,
-                    tol
-                ),
-                tol
-            )?)
-        }
+        self.point_at_param(param, true, tol)
     }
 
     /// Determines if input point lies on this arc.
@@ -134,11 +326,317 @@ impl Arc {
         }
     }
 
+    /// Returns an arc running in the opposite direction.
+    ///
+    /// The x axis of the new arc points to the old end point, so its start angle is 0.
+    pub fn reverse(&self) -> Self {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().reverse();
+        }
+
+        let (sin_e, cos_e) = self.end_angle.sin_cos();
+
+        Self { center_point: self.center_point,
+               x_axis: self.x_axis * cos_e + self.y_axis * sin_e,
+               y_axis: self.x_axis * sin_e - self.y_axis * cos_e,
+               radius: self.radius,
+               start_angle: 0.0,
+               end_angle: self.end_angle - self.start_angle }
+    }
+
+    /// Trims this arc to the angle interval from `start_param` to `end_param`.
+    pub fn trim(&self, start_param: f64, end_param: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().trim(start_param, end_param, tol);
+        }
+
+        if end_param <= start_param {
+            return Err(BgcError::InvalidInput);
+        }
+        if !self.is_param_in_range(start_param, tol) || !self.is_param_in_range(end_param, tol) {
+            return Err(BgcError::OutOfRange);
+        }
+        if (end_param - start_param) * self.radius < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Ok(Self { center_point: self.center_point,
+                  x_axis: self.x_axis,
+                  y_axis: self.y_axis,
+                  radius: self.radius,
+                  start_angle: start_param.max(self.start_angle),
+                  end_angle: end_param.min(self.end_angle) })
+    }
+
+    /// Splits this arc into two arcs at `param`.
+    pub fn split_at(&self, param: f64, tol: &Tolerance) -> Result<(Self, Self), BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().split_at(param, tol);
+        }
+
+        Ok((self.trim(self.start_angle, param, tol)?, self.trim(param, self.end_angle, tol)?))
+    }
+
+    /// Splits this arc into two arcs at input point.
+    pub fn split_at_point(&self, point: &Point, tol: &Tolerance) -> Result<(Self, Self), BgcError> {
+        if !self.contains(point, false, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let param = self.param_at_point(point, false, tol)?;
+        self.split_at(param, tol)
+    }
+
+    /// Transforms this arc to the coordinate system of the transformation matrix
+    ///
+    /// \[M\] * a = a'
+    ///
+    /// The matrix must keep circles circular, so non-uniform scaling and projective
+    /// matrices return `BgcError::InvalidInput`.
+    pub fn transform(&self, mat: &Matrix3d, tol: &Tolerance) -> Result<Self, BgcError> {
+        if !mat.is_affine(tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let center_point = self.center_point.transform(mat, tol)?;
+        let x_axis = self.x_axis.transform(mat);
+        let y_axis = self.y_axis.transform(mat);
+
+        let scale = x_axis.length();
+        if scale < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+        if (y_axis.length() - scale).abs() > tol.equal_vector() * scale
+                || x_axis.inner_product(&y_axis).abs() > tol.equal_vector() * scale * scale {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Ok(Self { center_point,
+                  x_axis: x_axis.normal(tol),
+                  y_axis: y_axis.normal(tol),
+                  radius: self.radius * scale,
+                  start_angle: self.start_angle,
+                  end_angle: self.end_angle })
+    }
+
+    /// Offsets this arc by `distance` within the reference plane.
+    ///
+    /// A positive distance moves the arc to the left of its direction, seen from the side
+    /// the plane normal points to. The center is kept and the radius changes.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::InvalidInput)` - the arc does not lie parallel to the plane
+    /// * `Err(BgcError::MustBePositive)` - the offset collapses the arc
+    pub fn offset(&self, distance: f64, plane: &Plane, tol: &Tolerance) -> Result<Self, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().offset(distance, plane, tol);
+        }
+
+        let arc_normal = self.x_axis.outer_product(&self.y_axis);
+        let plane_normal = plane.get_normal_vector(tol);
+        if !arc_normal.is_parallel_to(&plane_normal, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        // The center is on the left of a counterclockwise arc
+        let radius = if arc_normal.inner_product(&plane_normal) > 0.0 {
+            self.radius - distance
+        } else {
+            self.radius + distance
+        };
+        if radius < tol.equal_point() {
+            return Err(BgcError::MustBePositive);
+        }
+
+        Ok(Self { center_point: self.center_point,
+                  x_axis: self.x_axis,
+                  y_axis: self.y_axis,
+                  radius,
+                  start_angle: self.start_angle,
+                  end_angle: self.end_angle })
+    }
+
+    /// Extends this arc along its circle by `length` at the given end.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBeNoNegative)` - `length` is negative
+    /// * `Err(BgcError::OutOfRange)` - the arc would exceed a full circle
+    pub fn extend(&self, end: CurveEnd, length: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().extend(end, length, tol);
+        }
+
+        if length < 0.0 {
+            return Err(BgcError::MustBeNoNegative);
+        }
+
+        self.extend_by_angle(end, length / self.radius, tol)
+    }
+
+    /// Extends this arc along its circle at the given end until it meets the boundary curve.
+    ///
+    /// The nearest intersection beyond the end is used.
+    pub fn extend_to<T>(&self, end: CurveEnd, boundary: &T, tol: &Tolerance) -> Result<Self, BgcError>
+    where
+        T: Curve + ?Sized
+    {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().extend_to(end, boundary, tol);
+        }
+
+        let intersections = boundary.intersect_with_arc(&self.full_circle(), true, tol);
+        let points = extension::points_on_curve(intersections, boundary, tol)?;
+
+        self.extend_to_points(end, &points, tol)
+    }
+
+    /// Extends this arc along its circle at the given end until it meets the plane.
+    pub fn extend_to_plane(&self, end: CurveEnd, plane: &Plane, tol: &Tolerance) -> Result<Self, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().extend_to_plane(end, plane, tol);
+        }
+
+        let points = self.intersect_with_plane(plane, true, tol).unwrap_or_default();
+
+        self.extend_to_points(end, &points, tol)
+    }
+
+    /// Converts this arc to an exact rational quadratic curve.
+    ///
+    /// The arc is divided into pieces of at most 90 degrees. The knots are the angles of
+    /// the joints.
+    pub fn to_nurbs(&self, tol: &Tolerance) -> Result<NurbsCurve, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().to_nurbs(tol);
+        }
+
+        let sweep = self.end_angle - self.start_angle;
+        if sweep * self.radius < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let pieces = (sweep / std::f64::consts::FRAC_PI_2 - tol.calculation()).ceil().max(1.0) as usize;
+        let angle = sweep / pieces as f64;
+        let half = angle / 2.0;
+
+        let mut control_points = vec![self.start_point()];
+        let mut weights = vec![1.0];
+        let mut knots = vec![self.start_angle; 3];
+        for i in 0..pieces {
+            let a0 = self.start_angle + angle * i as f64;
+            let tangent = self.x_axis * -a0.sin() + self.y_axis * a0.cos();
+
+            control_points.push(self.calc_point_at_param(a0) + tangent * (self.radius * half.tan()));
+            weights.push(half.cos());
+            control_points.push(self.calc_point_at_param(a0 + angle));
+            weights.push(1.0);
+
+            let knot = if i + 1 == pieces { self.end_angle } else { a0 + angle };
+            let multiplicity = if i + 1 == pieces { 3 } else { 2 };
+            knots.extend(std::iter::repeat_n(knot, multiplicity));
+        }
+
+        NurbsCurve::new(2, control_points, weights, knots, tol)
+    }
+
+    /// Projects this arc onto the plane along `direction`, or orthogonally if it is `None`.
+    ///
+    /// The arc stays an arc if its plane is seen face-on. Seen edge-on, it becomes a line
+    /// covering the extent of the arc. Otherwise it becomes an elliptical arc.
+    pub fn project_to_plane(
+        &self,
+        plane: &Plane,
+        direction: Option<&Vector>,
+        tol: &Tolerance
+    ) -> Result<ProjectedCurve, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().project_to_plane(plane, direction, tol);
+        }
+
+        let center_point = plane.project_point(&self.center_point, direction, tol)?;
+        let x_axis = plane.project_vector(&self.x_axis, direction, tol)?;
+        let y_axis = plane.project_vector(&self.y_axis, direction, tol)?;
+
+        if x_axis.outer_product(&y_axis).length() < tol.equal_vector() {
+            return Ok(self.project_edge_on(&center_point, &x_axis, &y_axis, tol));
+        }
+
+        if (x_axis.length() - y_axis.length()).abs() < tol.equal_vector()
+                && x_axis.inner_product(&y_axis).abs() < tol.equal_vector() {
+            return Ok(ProjectedCurve::Arc(Self { center_point,
+                                                 x_axis: x_axis.normal(tol),
+                                                 y_axis: y_axis.normal(tol),
+                                                 radius: self.radius * x_axis.length(),
+                                                 start_angle: self.start_angle,
+                                                 end_angle: self.end_angle }));
+        }
+
+        Ok(ProjectedCurve::Ellipse(Ellipse::from_conjugate_axes(&center_point,
+                                                                &(x_axis * self.radius),
+                                                                &(y_axis * self.radius),
+                                                                self.start_angle,
+                                                                self.end_angle,
+                                                                tol)?))
+    }
+
+    /// Projects this arc onto a line through `center_point` when the projected axes are
+    /// parallel.
+    fn project_edge_on(
+        &self,
+        center_point: &Point,
+        x_axis: &Vector,
+        y_axis: &Vector,
+        tol: &Tolerance
+    ) -> ProjectedCurve {
+        let axis = if x_axis.length() >= y_axis.length() { x_axis } else { y_axis };
+        if axis.length() < tol.equal_vector() {
+            return ProjectedCurve::Point(*center_point);
+        }
+        let axis = axis.normal(tol);
+
+        // The position along the line is r(a cos(t) + b sin(t)), extreme at atan2(b, a) + kπ
+        let a = x_axis.inner_product(&axis);
+        let b = y_axis.inner_product(&axis);
+        let critical = b.atan2(a);
+
+        let mut params = vec![self.start_angle, self.end_angle];
+        let first = ((self.start_angle - critical) / std::f64::consts::PI).floor() as i64;
+        let last = ((self.end_angle - critical) / std::f64::consts::PI).ceil() as i64;
+        for k in first..=last {
+            let t = critical + std::f64::consts::PI * k as f64;
+            if self.start_angle < t && t < self.end_angle {
+                params.push(t);
+            }
+        }
+
+        let positions: Vec<f64> = params.iter().map(|t| self.radius * (a * t.cos() + b * t.sin())).collect();
+        let min = positions.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = positions.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        if max - min < tol.equal_point() {
+            ProjectedCurve::Point(*center_point + axis * min)
+        } else {
+            ProjectedCurve::Line(Line::new(*center_point + axis * min, *center_point + axis * max))
+        }
+    }
+
     pub fn containing_plane(&self, tol: &Tolerance) -> Plane {
         let z_axis = self.x_axis.outer_product(&self.y_axis);
         Plane::from(&self.center_point, &z_axis, tol)
     }
 
+    /// Chooses the x axis of a plane from its unit normal by the arbitrary axis algorithm
+    /// of DXF.
+    fn arbitrary_x_axis(normal: &Vector, tol: &Tolerance) -> Vector {
+        if normal.x.abs() < 1.0 / 64.0 && normal.y.abs() < 1.0 / 64.0 {
+            Vector::y_axis().outer_product(normal).normal(tol)
+        } else {
+            Vector::z_axis().outer_product(normal).normal(tol)
+        }
+    }
+
     fn calc_angle_at_local_point(p: &Point) -> f64 {
         let angle = p.y.atan2(p.x);
         if angle < 0.0 {
@@ -152,8 +650,88 @@ impl Arc {
         self.center_point + (self.x_axis * param.cos() + self.y_axis * param.sin()) * self.radius
     }
 
-    fn calc_length_at_param(&self, param: f64) -> f64 {
-        (param - self.start_angle) * self.radius
+    /// Returns the full circle on the same frame as this arc.
+    fn full_circle(&self) -> Self {
+        Self { center_point: self.center_point,
+               x_axis: self.x_axis,
+               y_axis: self.y_axis,
+               radius: self.radius,
+               start_angle: 0.0,
+               end_angle: std::f64::consts::PI * 2.0 }
+    }
+
+    /// Returns the arc from `start_angle` to `end_angle` of the circle of this arc.
+    ///
+    /// If the angles leave [0, 2π], the frame is rotated so that the new arc starts at 0.
+    fn with_angles(&self, start_angle: f64, end_angle: f64) -> Self {
+        if 0.0 <= start_angle && end_angle <= std::f64::consts::PI * 2.0 {
+            return Self { center_point: self.center_point,
+                          x_axis: self.x_axis,
+                          y_axis: self.y_axis,
+                          radius: self.radius,
+                          start_angle,
+                          end_angle };
+        }
+
+        let (sin_s, cos_s) = start_angle.sin_cos();
+        Self { center_point: self.center_point,
+               x_axis: self.x_axis * cos_s + self.y_axis * sin_s,
+               y_axis: self.x_axis * -sin_s + self.y_axis * cos_s,
+               radius: self.radius,
+               start_angle: 0.0,
+               end_angle: end_angle - start_angle }
+    }
+
+    fn extend_by_angle(&self, end: CurveEnd, angle: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        let (start_angle, end_angle) = match end {
+            CurveEnd::Start => (self.start_angle - angle, self.end_angle),
+            CurveEnd::End => (self.start_angle, self.end_angle + angle),
+        };
+        if end_angle - start_angle > std::f64::consts::PI * 2.0 + tol.calculation() {
+            return Err(BgcError::OutOfRange);
+        }
+
+        Ok(self.with_angles(start_angle, end_angle))
+    }
+
+    fn extend_to_points(&self, end: CurveEnd, points: &[Point], tol: &Tolerance) -> Result<Self, BgcError> {
+        let full = std::f64::consts::PI * 2.0;
+        let free = full - (self.end_angle - self.start_angle);
+
+        let mut nearest: Option<f64> = None;
+        for p in points {
+            let angle = self.param_at_point(p, true, tol)?;
+            let mut delta = match end {
+                CurveEnd::Start => self.start_angle - angle,
+                CurveEnd::End => angle - self.end_angle,
+            }.rem_euclid(full);
+            if full - delta < tol.calculation() {
+                delta = 0.0;
+            }
+
+            // Points on the arc itself are not beyond the end
+            if delta <= free + tol.calculation() && nearest.is_none_or(|n| delta < n) {
+                nearest = Some(delta);
+            }
+        }
+
+        let angle = nearest.ok_or(BgcError::InvalidInput)?;
+        self.extend_by_angle(end, angle, tol)
+    }
+
+    /// Unwraps an angle in [0, 2π) to the parameter on or nearest to this arc.
+    ///
+    /// Outside the arc, the angle is taken after the end or before the start, whichever
+    /// is closer.
+    fn param_at_angle(&self, angle: f64, tol: &Tolerance) -> f64 {
+        let full = std::f64::consts::PI * 2.0;
+        let param = self.start_angle + (angle - self.start_angle).rem_euclid(full);
+
+        if param - self.end_angle > self.start_angle - (param - full) - tol.calculation() {
+            param - full
+        } else {
+            param
+        }
     }
 
     fn is_param_in_range(&self, param: f64, tol: &Tolerance) -> bool {
@@ -190,13 +768,13 @@ impl Arc {
         let p2 = other.point_at_dist(roots.1, true, tol)?;
 
         let mut points = Vec::new();
-        if self.is_param_in_range(Arc::calc_angle_at_local_point(&p1), tol) &&
+        if self.is_param_in_range(self.param_at_angle(Arc::calc_angle_at_local_point(&p1), tol), tol) &&
                 (extends || other.contains(&p1, false, tol)) {
             points.push(p1);
         }
 
         if !p1.is_equal_to(&p2, tol) &&
-                self.is_param_in_range(Arc::calc_angle_at_local_point(&p2), tol) &&
+                self.is_param_in_range(self.param_at_angle(Arc::calc_angle_at_local_point(&p2), tol), tol) &&
                 (extends || other.contains(&p2, false, tol)) {
             points.push(p2);
         }
@@ -285,12 +863,123 @@ impl Arc {
 }
 
 impl Curve for Arc {
+    /// The parameter is the angle in the local coordinate system of this arc, negated for
+    /// a clockwise arc.
+    fn domain(&self) -> (f64, f64) {
+        if self.is_clockwise() {
+            (-self.start_angle, -self.end_angle)
+        } else {
+            (self.start_angle, self.end_angle)
+        }
+    }
+
+    fn point_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Point, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().point_at_param(param, extends, tol);
+        }
+
+        if !extends && !self.is_param_in_range(param, tol) {
+            return Err(BgcError::OutOfRange);
+        }
+
+        Ok(self.calc_point_at_param(param))
+    }
+
+    /// C'(t) = r(-sin(t)X + cos(t)Y) <br>
+    /// C''(t) = -r(cos(t)X + sin(t)Y)
+    fn derivatives_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<(Vector, Vector), BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().derivatives_at_param(param, extends, tol);
+        }
+
+        if !extends && !self.is_param_in_range(param, tol) {
+            return Err(BgcError::OutOfRange);
+        }
+
+        let first = (self.x_axis * -param.sin() + self.y_axis * param.cos()) * self.radius;
+        let second = (self.x_axis * param.cos() + self.y_axis * param.sin()) * -self.radius;
+
+        Ok((first, second))
+    }
+
+    fn param_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().param_at_point(point, extends, tol);
+        }
+
+        let mut local_point = point.transform(
+            &Matrix3d::transform_to_local(
+                &self.center_point,
+                &self.x_axis,
+                &self.y_axis,
+                tol
+            ),
+            tol
+        )?;
+        local_point.z = 0.0;
+
+        let angle = self.param_at_angle(Arc::calc_angle_at_local_point(&local_point), tol);
+
+        if !extends && !self.is_param_in_range(angle, tol) {
+            let to_start = self.start_point().distance_to(point);
+            let to_end = self.end_point().distance_to(point);
+
+            if to_start - to_end <= tol.equal_point() {
+                Ok(self.start_angle)
+            } else {
+                Ok(self.end_angle)
+            }
+        } else {
+            Ok(angle)
+        }
+    }
+
+    fn length_between(
+        &self,
+        start_param: f64,
+        end_param: f64,
+        _tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        Ok((end_param - start_param) * self.radius)
+    }
+
+    fn param_at_length(&self, length: f64, tol: &Tolerance) -> Result<f64, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().param_at_length(length, tol);
+        }
+
+        if length < -tol.equal_point() || self.length() + tol.equal_point() < length {
+            return Err(BgcError::OutOfRange);
+        }
+
+        Ok((self.start_angle + length / self.radius).clamp(self.start_angle, self.end_angle))
+    }
+
     fn intersect_with_line(
         &self,
         other: &Line,
         extends: bool,
         tol: &Tolerance
     ) -> Result<Vec<Point>, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().intersect_with_line(other, extends, tol);
+        }
+
         let local_plane = self.containing_plane(tol);
 
         if other.is_parallel_with_plane(&local_plane, tol) {
@@ -340,6 +1029,10 @@ impl Curve for Arc {
         extends: bool,
         tol: &Tolerance
     ) -> Result<Vec<Point>, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().intersect_with_arc(other, extends, tol);
+        }
+
         let local_plane = self.containing_plane(tol);
         let other_plane = other.containing_plane(tol);
 
@@ -418,6 +1111,10 @@ impl Curve for Arc {
         extends: bool,
         tol: &Tolerance
     ) -> Result<Vec<Point>, BgcError> {
+        if self.is_clockwise() {
+            return self.to_counterclockwise().intersect_with_plane(other, extends, tol);
+        }
+
         let local_plane = self.containing_plane(tol);
 
         if local_plane.is_parallel_to(other, tol) {
@@ -1397,4 +2094,601 @@ mod tests  {
         let expected_y = (1.0e16_f64 - 25.0e14_f64).sqrt();
         assert!(result[0].is_equal_to(&Point::new(5.0e7, expected_y, 0.0), &tol));
     }
+
+    #[test]
+    fn arc_point_at_param() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::new(1.0, 2.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+
+        assert_eq!(arc.domain(), (0.0, std::f64::consts::PI));
+
+        let p = arc.point_at_param(std::f64::consts::FRAC_PI_2, false, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(1.0, 4.0, 0.0), &tol));
+
+        let param = std::f64::consts::PI * 1.5;
+        assert_eq!(arc.point_at_param(param, false, &tol).unwrap_err(), BgcError::OutOfRange);
+        let p = arc.point_at_param(param, true, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+
+        // Derivatives compared with central differences
+        let t = 0.7;
+        let h = 1.0e-4;
+        let (d1, d2) = arc.derivatives_at_param(t, false, &tol).unwrap();
+        let p_prev = arc.point_at_param(t - h, false, &tol).unwrap();
+        let p_mid = arc.point_at_param(t, false, &tol).unwrap();
+        let p_next = arc.point_at_param(t + h, false, &tol).unwrap();
+        let fd1 = (p_next - p_prev) * (0.5 / h);
+        let fd2 = (Vector::from(p_next) - Vector::from(p_mid) * 2.0 + Vector::from(p_prev))
+            * (1.0 / (h * h));
+        assert!((d1 - fd1).length() < 1.0e-6);
+        assert!((d2 - fd2).length() < 1.0e-3);
+    }
+
+    #[test]
+    fn arc_param_at_point() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 5.0,
+            start_angle: std::f64::consts::FRAC_PI_4,
+            end_angle: std::f64::consts::PI * 0.75,
+        };
+
+        let t = arc.param_at_point(&Point::new(0.0, 10.0, 3.0), false, &tol).unwrap();
+        assert!((t - std::f64::consts::FRAC_PI_2).abs() < tol.calculation());
+
+        let t = arc.param_at_point(&Point::new(10.0, 0.0, 0.0), false, &tol).unwrap();
+        assert!((t - arc.start_angle).abs() < tol.calculation());
+
+        let t = arc.param_at_point(&Point::new(10.0, 0.0, 0.0), true, &tol).unwrap();
+        assert!(t.abs() < tol.calculation());
+    }
+
+    #[test]
+    fn arc_reverse() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::new(1.0, 1.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: std::f64::consts::FRAC_PI_4,
+            end_angle: std::f64::consts::PI,
+        };
+
+        let reversed = arc.reverse();
+        assert!(reversed.start_point().is_equal_to(&arc.end_point(), &tol));
+        assert!(reversed.end_point().is_equal_to(&arc.start_point(), &tol));
+        assert!((reversed.length() - arc.length()).abs() < tol.equal_point());
+        assert!((reversed.start_angle - 0.0).abs() < tol.calculation());
+
+        // The middle point is the same, the normal is flipped
+        let mid = arc.point_at_param(std::f64::consts::PI * 0.625, false, &tol).unwrap();
+        assert!(reversed.contains(&mid, false, &tol));
+        let normal = arc.x_axis.outer_product(&arc.y_axis);
+        let reversed_normal = reversed.x_axis.outer_product(&reversed.y_axis);
+        assert!(reversed_normal.is_equal_to(&(normal * -1.0), &tol));
+    }
+
+    #[test]
+    fn arc_trim() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 5.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+
+        let trimmed = arc.trim(std::f64::consts::FRAC_PI_4, std::f64::consts::FRAC_PI_2, &tol)
+            .unwrap();
+        assert!(trimmed.end_point().is_equal_to(&Point::new(0.0, 5.0, 0.0), &tol));
+        assert!((trimmed.length() - 5.0 * std::f64::consts::FRAC_PI_4).abs() < tol.equal_point());
+
+        assert_eq!(
+            arc.trim(1.0, std::f64::consts::PI * 1.5, &tol).unwrap_err(),
+            BgcError::OutOfRange
+        );
+        assert_eq!(arc.trim(2.0, 1.0, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn arc_split() {
+        let tol = Tolerance::default();
+        let arc = Arc::from_three_points(
+            &Point::new(5.0, 0.0, 0.0),
+            &Point::new(-5.0, 0.0, 0.0),
+            &Point::new(0.0, 5.0, 0.0),
+            &tol
+        ).unwrap();
+
+        let (first, second) = arc.split_at_point(&Point::new(3.0, 4.0, 0.0), &tol).unwrap();
+        assert!(first.start_point().is_equal_to(&Point::new(5.0, 0.0, 0.0), &tol));
+        assert!(first.end_point().is_equal_to(&Point::new(3.0, 4.0, 0.0), &tol));
+        assert!(second.start_point().is_equal_to(&Point::new(3.0, 4.0, 0.0), &tol));
+        assert!(second.end_point().is_equal_to(&Point::new(-5.0, 0.0, 0.0), &tol));
+        assert!((first.length() + second.length() - arc.length()).abs() < tol.equal_point());
+
+        assert_eq!(arc.split_at(arc.end_angle, &tol).unwrap_err(), BgcError::InvalidInput);
+        assert_eq!(
+            arc.split_at_point(&Point::new(3.0, -4.0, 0.0), &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn arc_transform() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::new(1.0, 0.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::FRAC_PI_2,
+        };
+
+        // Rotation and translation
+        let mat = Matrix3d::transform_to_world(
+            &Point::new(10.0, 20.0, 30.0),
+            &Vector::new(0.0, 1.0, 0.0),
+            &Vector::new(0.0, 0.0, 1.0),
+            &tol
+        );
+        let moved = arc.transform(&mat, &tol).unwrap();
+        assert!(moved.center_point.is_equal_to(&Point::new(10.0, 21.0, 30.0), &tol));
+        assert!((moved.radius - 2.0).abs() < tol.equal_point());
+        assert!(moved.start_point().is_equal_to(
+            &arc.start_point().transform(&mat, &tol).unwrap(),
+            &tol
+        ));
+        assert!(moved.end_point().is_equal_to(
+            &arc.end_point().transform(&mat, &tol).unwrap(),
+            &tol
+        ));
+
+        // Uniform scaling with mirroring
+        let mut mat = Matrix3d::identity();
+        mat.set(0, 0, -3.0);
+        mat.set(1, 1, 3.0);
+        mat.set(2, 2, 3.0);
+        let scaled = arc.transform(&mat, &tol).unwrap();
+        assert!((scaled.radius - 6.0).abs() < tol.equal_point());
+        assert!(scaled.start_point().is_equal_to(&Point::new(-9.0, 0.0, 0.0), &tol));
+        assert!(scaled.end_point().is_equal_to(&Point::new(-3.0, 6.0, 0.0), &tol));
+
+        // Non-uniform scaling
+        let mut mat = Matrix3d::identity();
+        mat.set(0, 0, 2.0);
+        assert_eq!(arc.transform(&mat, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn arc_offset() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+        let arc = Arc {
+            center_point: Point::new(1.0, 1.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 5.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::FRAC_PI_2,
+        };
+
+        // The arc is counterclockwise, so the left side is inward
+        let inner = arc.offset(2.0, &plane, &tol).unwrap();
+        assert!((inner.radius - 3.0).abs() < tol.equal_point());
+        assert!(inner.center_point.is_equal_to(&arc.center_point, &tol));
+        assert!(inner.start_point().is_equal_to(&Point::new(4.0, 1.0, 0.0), &tol));
+
+        let outer = arc.offset(-2.0, &plane, &tol).unwrap();
+        assert!((outer.radius - 7.0).abs() < tol.equal_point());
+
+        let reversed = arc.reverse().offset(2.0, &plane, &tol).unwrap();
+        assert!((reversed.radius - 7.0).abs() < tol.equal_point());
+
+        // Collapsed arcs
+        assert_eq!(arc.offset(5.0, &plane, &tol).unwrap_err(), BgcError::MustBePositive);
+        assert_eq!(arc.offset(8.0, &plane, &tol).unwrap_err(), BgcError::MustBePositive);
+
+        // The arc is not parallel to the plane
+        let vertical = Plane::from(&Point::origin(), &Vector::x_axis(), &tol);
+        assert_eq!(arc.offset(1.0, &vertical, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn arc_extend() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: std::f64::consts::FRAC_PI_2,
+            end_angle: std::f64::consts::PI,
+        };
+
+        let extended = arc.extend(CurveEnd::End, std::f64::consts::PI, &tol).unwrap();
+        assert!(extended.start_point().is_equal_to(&arc.start_point(), &tol));
+        assert!(extended.end_point().is_equal_to(&Point::new(0.0, -2.0, 0.0), &tol));
+        assert!((extended.length() - arc.length() - std::f64::consts::PI).abs() < tol.equal_point());
+
+        // Extending over the seam rotates the frame
+        let extended = arc.extend(CurveEnd::Start, std::f64::consts::PI * 2.0, &tol).unwrap();
+        assert!(extended.start_point().is_equal_to(&Point::new(0.0, -2.0, 0.0), &tol));
+        assert!(extended.end_point().is_equal_to(&arc.end_point(), &tol));
+        assert!(extended.contains(&Point::new(2.0, 0.0, 0.0), false, &tol));
+        assert!(!extended.contains(&Point::new(0.0, 2.0, 0.0).calc_middle_point(&Point::new(2.0, 0.0, 0.0)), false, &tol));
+
+        assert_eq!(arc.extend(CurveEnd::End, 10.0, &tol).unwrap_err(), BgcError::OutOfRange);
+    }
+
+    #[test]
+    fn arc_extend_to_boundary() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: std::f64::consts::FRAC_PI_2,
+            end_angle: std::f64::consts::PI,
+        };
+
+        let boundary = Line::new(Point::new(1.0, -5.0, 0.0), Point::new(1.0, 5.0, 0.0));
+        let extended = arc.extend_to(CurveEnd::End, &boundary, &tol).unwrap();
+        assert!(extended.end_point().is_equal_to(&Point::new(1.0, -(3.0_f64.sqrt()), 0.0), &tol));
+        let extended = arc.extend_to(CurveEnd::Start, &boundary, &tol).unwrap();
+        assert!(extended.start_point().is_equal_to(&Point::new(1.0, 3.0_f64.sqrt(), 0.0), &tol));
+        assert!(extended.end_point().is_equal_to(&arc.end_point(), &tol));
+
+        let other = Arc {
+            center_point: Point::new(2.0, -2.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: std::f64::consts::FRAC_PI_2,
+            end_angle: std::f64::consts::PI,
+        };
+        let extended = arc.extend_to(CurveEnd::End, &other, &tol).unwrap();
+        assert!(extended.end_point().is_equal_to(&Point::new(0.0, -2.0, 0.0), &tol));
+
+        let plane = Plane::from(&Point::origin(), &Vector::y_axis(), &tol);
+        let extended = arc.extend_to_plane(CurveEnd::End, &plane, &tol).unwrap();
+        assert!(extended.end_point().is_equal_to(&Point::new(-2.0, 0.0, 0.0), &tol));
+        let extended = arc.extend_to_plane(CurveEnd::Start, &plane, &tol).unwrap();
+        assert!(extended.start_point().is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+
+        let far = Line::new(Point::new(5.0, -5.0, 0.0), Point::new(5.0, 5.0, 0.0));
+        assert_eq!(arc.extend_to(CurveEnd::End, &far, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn arc_to_nurbs() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::new(1.0, 2.0, 3.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::z_axis(),
+            radius: 2.0,
+            start_angle: 0.5,
+            end_angle: 4.0,
+        };
+
+        let curve = arc.to_nurbs(&tol).unwrap();
+        assert_eq!(curve.domain(), (0.5, 4.0));
+        assert_eq!(curve.control_points().len(), 2 * 3 + 1);
+        assert!(curve.start_point(&tol).unwrap().is_equal_to(&arc.start_point(), &tol));
+        assert!(curve.end_point(&tol).unwrap().is_equal_to(&arc.end_point(), &tol));
+        for i in 0..=20 {
+            let p = curve.evaluate(0.5 + 3.5 * i as f64 / 20.0, &tol).unwrap();
+            assert!((p.distance_to(&arc.center_point) - 2.0).abs() < tol.calculation());
+            assert!(arc.contains(&p, false, &tol));
+        }
+    }
+
+    #[test]
+    fn arc_project_to_plane() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::new(0.0, 0.0, 2.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+
+        // Face-on, also along an oblique direction
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+        let direction = Vector::new(1.0, 0.0, 1.0);
+        let ProjectedCurve::Arc(projected) = arc.project_to_plane(&plane, Some(&direction), &tol).unwrap() else {
+            panic!("the projection should be an arc");
+        };
+        assert!(projected.center_point.is_equal_to(&Point::new(-2.0, 0.0, 0.0), &tol));
+        assert!((projected.radius - 2.0).abs() < tol.equal_point());
+        assert!(projected.end_point().is_equal_to(&Point::new(-4.0, 0.0, 0.0), &tol));
+
+        // Edge-on, the top of the arc is the extent
+        let side = Plane::from(&Point::origin(), &Vector::x_axis(), &tol);
+        let ProjectedCurve::Line(projected) = arc.project_to_plane(&side, None, &tol).unwrap() else {
+            panic!("the projection should be a line");
+        };
+        assert!(projected.start_point.is_equal_to(&Point::new(0.0, 0.0, 2.0), &tol));
+        assert!(projected.end_point.is_equal_to(&Point::new(0.0, 2.0, 2.0), &tol));
+
+        // Tilted, the arc becomes elliptical
+        let tilted = Plane::from(&Point::origin(), &Vector::new(1.0, 0.0, 1.0), &tol);
+        let ProjectedCurve::Ellipse(projected) = arc.project_to_plane(&tilted, None, &tol).unwrap() else {
+            panic!("the projection should be an elliptical arc");
+        };
+        assert!((projected.major_radius() - 2.0).abs() < tol.equal_point());
+        assert!((projected.minor_radius() - 2.0_f64.sqrt()).abs() < tol.equal_point());
+        assert!(projected.start_point().is_equal_to(&tilted.closest_point(&arc.start_point()), &tol));
+        assert!(projected.end_point().is_equal_to(&tilted.closest_point(&arc.end_point()), &tol));
+        for i in 0..=10 {
+            let t = std::f64::consts::PI * i as f64 / 10.0;
+            let expected = tilted.closest_point(&arc.point_at_param(t, false, &tol).unwrap());
+            assert!(projected.contains(&expected, false, &tol));
+        }
+    }
+
+    fn mid_point(arc: &Arc, tol: &Tolerance) -> Point {
+        arc.point_at_param((arc.start_angle + arc.end_angle) / 2.0, false, tol).unwrap()
+    }
+
+    #[test]
+    fn arc_from_center_radius_angles() {
+        let tol = Tolerance::default();
+        let center = Point::new(1.0, 1.0, 0.0);
+
+        let arc = Arc::from_center_radius_angles(
+            &center, &Vector::z_axis(), 2.0, 0.0, std::f64::consts::FRAC_PI_2, &tol
+        ).unwrap();
+        assert!(arc.start_point().is_equal_to(&Point::new(3.0, 1.0, 0.0), &tol));
+        assert!(arc.end_point().is_equal_to(&Point::new(1.0, 3.0, 0.0), &tol));
+
+        // Wraps through 0
+        let arc = Arc::from_center_radius_angles(
+            &center, &Vector::z_axis(), 2.0, std::f64::consts::PI * 1.5, std::f64::consts::FRAC_PI_2, &tol
+        ).unwrap();
+        assert!(arc.start_point().is_equal_to(&Point::new(1.0, -1.0, 0.0), &tol));
+        assert!(arc.end_point().is_equal_to(&Point::new(1.0, 3.0, 0.0), &tol));
+        assert!(mid_point(&arc, &tol).is_equal_to(&Point::new(3.0, 1.0, 0.0), &tol));
+        assert!((arc.length() - std::f64::consts::PI * 2.0).abs() < tol.calculation());
+
+        // The arbitrary axis of a normal along -Y is the X axis
+        let arc = Arc::from_center_radius_angles(
+            &Point::origin(), &(Vector::y_axis() * -1.0), 1.0, 0.0, std::f64::consts::FRAC_PI_2, &tol
+        ).unwrap();
+        assert!(arc.start_point().is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!(arc.end_point().is_equal_to(&Point::new(0.0, 0.0, 1.0), &tol));
+
+        let normal = Vector::z_axis();
+        assert_eq!(
+            Arc::from_center_radius_angles(&center, &normal, 0.0, 0.0, 1.0, &tol).unwrap_err(),
+            BgcError::MustBePositive
+        );
+        assert_eq!(
+            Arc::from_center_radius_angles(&center, &normal, 1.0, 1.0, 1.0, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+        assert_eq!(
+            Arc::from_center_radius_angles(&center, &normal, 1.0, 0.0, 7.0, &tol).unwrap_err(),
+            BgcError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn arc_from_center_start_end() {
+        let tol = Tolerance::default();
+        let center = Point::origin();
+        let start = Point::new(1.0, 0.0, 0.0);
+        let end = Point::new(0.0, 1.0, 0.0);
+
+        let ccw = Arc::from_center_start_end(
+            &center, &start, &end, &Vector::z_axis(), SweepDirection::CounterClockwise, &tol
+        ).unwrap();
+        assert!((ccw.end_angle - std::f64::consts::FRAC_PI_2).abs() < tol.calculation());
+        assert!(ccw.end_point().is_equal_to(&end, &tol));
+
+        let cw = Arc::from_center_start_end(
+            &center, &start, &end, &Vector::z_axis(), SweepDirection::Clockwise, &tol
+        ).unwrap();
+        assert!((cw.end_angle - std::f64::consts::PI * 1.5).abs() < tol.calculation());
+        assert!(cw.start_point().is_equal_to(&start, &tol));
+        assert!(cw.end_point().is_equal_to(&end, &tol));
+        let expected = Point::new(-std::f64::consts::FRAC_1_SQRT_2, -std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        assert!(mid_point(&cw, &tol).is_equal_to(&expected, &tol));
+
+        let far = Point::new(0.0, 2.0, 0.0);
+        assert_eq!(
+            Arc::from_center_start_end(
+                &center, &start, &far, &Vector::z_axis(), SweepDirection::CounterClockwise, &tol
+            ).unwrap_err(),
+            BgcError::InvalidInput
+        );
+        assert_eq!(
+            Arc::from_center_start_end(
+                &center, &start, &end, &Vector::x_axis(), SweepDirection::CounterClockwise, &tol
+            ).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn arc_from_start_tangent_end() {
+        let tol = Tolerance::default();
+        let start = Point::new(1.0, 0.0, 0.0);
+
+        let arc = Arc::from_start_tangent_end(&start, &Vector::y_axis(), &Point::new(-1.0, 0.0, 0.0), &tol).unwrap();
+        assert!(arc.center_point.is_equal_to(&Point::origin(), &tol));
+        assert!((arc.radius - 1.0).abs() < tol.calculation());
+        assert!((arc.end_angle - std::f64::consts::PI).abs() < tol.calculation());
+        assert!(mid_point(&arc, &tol).is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+
+        // Turning right, the arc runs clockwise seen from +Z
+        let arc = Arc::from_start_tangent_end(&start, &Vector::y_axis(), &Point::new(3.0, 0.0, 0.0), &tol).unwrap();
+        assert!(arc.center_point.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(mid_point(&arc, &tol).is_equal_to(&Point::new(2.0, 1.0, 0.0), &tol));
+        let (d1, _) = arc.derivatives_at_param(0.0, false, &tol).unwrap();
+        assert!(d1.normal(&tol).is_equal_to(&Vector::y_axis(), &tol));
+
+        // A short chord barely off the tangent still gives unit axes
+        let end = Point::new(1.0 - 5.0e-8, 0.01, 0.0);
+        let arc = Arc::from_start_tangent_end(&start, &Vector::y_axis(), &end, &tol).unwrap();
+        assert!((arc.x_axis.length() - 1.0).abs() < tol.calculation());
+        assert!(arc.end_point().is_equal_to(&end, &tol));
+
+        assert_eq!(
+            Arc::from_start_tangent_end(&start, &Vector::x_axis(), &Point::new(3.0, 0.0, 0.0), &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn arc_from_bulge() {
+        let tol = Tolerance::default();
+        let start = Point::new(1.0, 0.0, 0.0);
+        let end = Point::new(-1.0, 0.0, 0.0);
+
+        // A bulge of 1 is a half circle
+        let arc = Arc::from_bulge(&start, &end, 1.0, &Vector::z_axis(), &tol).unwrap();
+        assert!(arc.center_point.is_equal_to(&Point::origin(), &tol));
+        assert!(mid_point(&arc, &tol).is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+
+        let arc = Arc::from_bulge(&start, &end, -1.0, &Vector::z_axis(), &tol).unwrap();
+        assert!(mid_point(&arc, &tol).is_equal_to(&Point::new(0.0, -1.0, 0.0), &tol));
+        assert!(arc.end_point().is_equal_to(&end, &tol));
+
+        // Three quarters of a circle
+        let bulge = (std::f64::consts::PI * 1.5 / 4.0).tan();
+        let arc = Arc::from_bulge(&start, &Point::new(0.0, -1.0, 0.0), bulge, &Vector::z_axis(), &tol).unwrap();
+        assert!(arc.center_point.is_equal_to(&Point::origin(), &tol));
+        assert!((arc.end_angle - arc.start_angle - std::f64::consts::PI * 1.5).abs() < tol.calculation());
+
+        assert_eq!(
+            Arc::from_bulge(&start, &end, 0.0, &Vector::z_axis(), &tol).unwrap_err(),
+            BgcError::MustBeNonZero
+        );
+        assert_eq!(
+            Arc::from_bulge(&start, &start, 1.0, &Vector::z_axis(), &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn arc_across_seam() {
+        let tol = Tolerance::default();
+        let right_half = |start_angle: f64, end_angle: f64| Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle,
+            end_angle,
+        };
+
+        for arc in [
+            right_half(std::f64::consts::PI * 1.5, std::f64::consts::PI * 2.5),
+            right_half(-std::f64::consts::FRAC_PI_2, std::f64::consts::FRAC_PI_2),
+        ] {
+            assert!((arc.length() - std::f64::consts::PI).abs() < tol.calculation());
+            assert!(arc.start_point().is_equal_to(&Point::new(0.0, -1.0, 0.0), &tol));
+            assert!(mid_point(&arc, &tol).is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+
+            // Both sides of the seam are on the arc, the left half is not
+            assert!(arc.contains(&Point::new(1.0, 0.0, 0.0), false, &tol));
+            let below = Point::new((-0.1_f64).cos(), (-0.1_f64).sin(), 0.0);
+            assert!(arc.contains(&below, false, &tol));
+            assert!(!arc.contains(&Point::new(-1.0, 0.0, 0.0), false, &tol));
+
+            let t = arc.param_at_point(&Point::new(2.0, 0.0, 0.0), false, &tol).unwrap();
+            assert!(arc.point_at_param(t, false, &tol).unwrap().is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+            let closest = arc.closest_point(&Point::new(-2.0, -0.5, 0.0), false, &tol).unwrap();
+            assert!(closest.is_equal_to(&arc.start_point(), &tol));
+
+            let line = Line::new(Point::new(-2.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0));
+            let points = arc.intersect_with_line(&line, false, &tol).unwrap();
+            assert_eq!(points.len(), 1);
+            assert!(points[0].is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+
+            let plane = Plane::from(&Point::new(0.5, 0.0, 0.0), &Vector::x_axis(), &tol);
+            let points = arc.intersect_with_plane(&plane, false, &tol).unwrap();
+            assert_eq!(points.len(), 2);
+        }
+    }
+
+    #[test]
+    fn arc_clockwise() {
+        let tol = Tolerance::default();
+
+        // The right half of the unit circle, from the top down through +X
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: std::f64::consts::FRAC_PI_2,
+            end_angle: -std::f64::consts::FRAC_PI_2,
+        };
+        assert!(arc.is_clockwise());
+        assert!((arc.sweep_angle() + std::f64::consts::PI).abs() < tol.calculation());
+        assert!((arc.length() - std::f64::consts::PI).abs() < tol.calculation());
+
+        let (start, end) = arc.domain();
+        assert!(start < end);
+        assert!(arc.point_at_param(start, false, &tol).unwrap().is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        assert!(arc.point_at_param(end, false, &tol).unwrap().is_equal_to(&Point::new(0.0, -1.0, 0.0), &tol));
+        let (d1, _) = arc.derivatives_at_param(start, false, &tol).unwrap();
+        assert!(d1.is_equal_to(&Vector::x_axis(), &tol));
+
+        assert!(arc.contains(&Point::new(1.0, 0.0, 0.0), false, &tol));
+        assert!(!arc.contains(&Point::new(-1.0, 0.0, 0.0), false, &tol));
+        let closest = arc.closest_point(&Point::new(3.0, 3.0, 1.0), false, &tol).unwrap();
+        let diagonal = Point::new(std::f64::consts::FRAC_1_SQRT_2, std::f64::consts::FRAC_1_SQRT_2, 0.0);
+        assert!(closest.is_equal_to(&diagonal, &tol));
+
+        let line = Line::new(Point::new(-2.0, 0.5, 0.0), Point::new(2.0, 0.5, 0.0));
+        let points = arc.intersect_with_line(&line, false, &tol).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].x > 0.0);
+
+        let other = Arc {
+            center_point: Point::new(1.0, 0.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: std::f64::consts::PI,
+            end_angle: 0.0,
+        };
+        let points = arc.intersect_with_arc(&other, false, &tol).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].is_equal_to(&Point::new(0.5, 0.75_f64.sqrt(), 0.0), &tol));
+
+        // New arcs keep the direction of travel
+        let reversed = arc.reverse();
+        assert!(reversed.start_point().is_equal_to(&arc.end_point(), &tol));
+        assert!(mid_point(&reversed, &tol).is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+
+        let (first, second) = arc.split_at(start + std::f64::consts::FRAC_PI_2, &tol).unwrap();
+        assert!(first.start_point().is_equal_to(&arc.start_point(), &tol));
+        assert!(first.end_point().is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!(second.end_point().is_equal_to(&arc.end_point(), &tol));
+
+        let extended = arc.extend(CurveEnd::End, std::f64::consts::FRAC_PI_2, &tol).unwrap();
+        assert!(extended.end_point().is_equal_to(&Point::new(-1.0, 0.0, 0.0), &tol));
+    }
 }
@@ -0,0 +1,285 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// Subdivision fails at this depth if the deviation is not satisfied.
+const MAX_DEPTH: usize = 24;
+
+/// Number of points of the curve checked against each biarc.
+const SAMPLES_PER_SPAN: usize = 8;
+
+/// Approximates a curve by biarcs within `tol.equal_point()`.
+///
+/// Each span between breakpoints is bisected recursively until the biarc fitted to the
+/// ends and tangents of every piece is close enough to the curve.
+pub(crate) fn approximate<C: Curve + ?Sized>(curve: &C, tol: &Tolerance) -> Result<ArcSpline, BgcError> {
+    let breaks = curve.breakpoints();
+    if breaks.is_empty() {
+        return Err(BgcError::Empty);
+    }
+
+    let mut spline = ArcSpline { segments: Vec::new(), max_deviation: 0.0 };
+    for span in breaks.windows(2) {
+        let start = measure::sample_at(curve, span[0], tol)?;
+        let end = measure::sample_at(curve, span[1], tol)?;
+        subdivide(curve, &start, &end, 0, tol, &mut spline)?;
+    }
+
+    Ok(spline)
+}
+
+/// Pushes the segments from `start` to `end`.
+fn subdivide<C: Curve + ?Sized>(
+    curve: &C,
+    start: &CurveSample,
+    end: &CurveSample,
+    depth: usize,
+    tol: &Tolerance,
+    spline: &mut ArcSpline
+) -> Result<(), BgcError> {
+    if let Some(segments) = fit(start, end, tol)? {
+        let deviation = deviation(curve, start.param, end.param, &segments, tol)?;
+        if deviation <= tol.equal_point() {
+            spline.max_deviation = spline.max_deviation.max(deviation);
+            spline.segments.extend(segments);
+            return Ok(());
+        }
+    }
+    if depth >= MAX_DEPTH {
+        return Err(BgcError::Deivergence);
+    }
+
+    let mid = measure::sample_at(curve, (start.param + end.param) / 2.0, tol)?;
+
+    subdivide(curve, start, &mid, depth + 1, tol, spline)?;
+    subdivide(curve, &mid, end, depth + 1, tol, spline)
+}
+
+/// Fits the biarc with equal tangent lengths to the ends and tangents.
+///
+/// v = P1 - P0, t = T0 + T1 <br>
+/// |v - d\*t| = 2d  =>  2(1 - T0.T1)d^2 + 2(v.t)d - v.v = 0 <br>
+/// J = (P0 + d\*T0 + P1 - d\*T1)/2
+///
+/// Returns `None` if no biarc fits, and a single line if the piece is straight. A piece
+/// whose ends meet gives no segments, which only passes the deviation check if the piece
+/// is a point.
+fn fit(start: &CurveSample, end: &CurveSample, tol: &Tolerance) -> Result<Option<Vec<Segment>>, BgcError> {
+    let v = end.point - start.point;
+    let chord = v.length();
+    if chord < tol.calculation() {
+        // The piece collapses to a point
+        return Ok(Some(Vec::new()));
+    }
+
+    let chord_direction = v * (1.0 / chord);
+    if start.tangent.is_equal_to(&chord_direction, tol) && end.tangent.is_equal_to(&chord_direction, tol) {
+        return Ok(Some(vec![Segment::Line(Line::new(start.point, end.point))]));
+    }
+
+    // The stable root of the quadratic, positive whenever the denominator is
+    let a = 2.0 * (1.0 - start.tangent.inner_product(&end.tangent));
+    let b = 2.0 * v.inner_product(&(start.tangent + end.tangent));
+    let denominator = b + (b * b + 4.0 * a * chord * chord).sqrt();
+    if denominator < tol.calculation() * chord {
+        return Ok(None);
+    }
+    let d = 2.0 * chord * chord / denominator;
+
+    let joint = start.point + (start.tangent - end.tangent) * (d / 2.0) + v * 0.5;
+    let joint_tangent = (end.point - end.tangent * d) - (start.point + start.tangent * d);
+
+    // Pieces shorter than `tol.equal_point()` are still arcs, so the spline stays G1
+    let fine = fine_tolerance(tol);
+    let mut segments = Vec::with_capacity(2);
+    for (from, tangent, to) in [(start.point, start.tangent, joint), (joint, joint_tangent, end.point)] {
+        if from.is_equal_to(&to, &fine) {
+            continue;
+        }
+        segments.push(piece(&from, &tangent, &to, &fine)?);
+    }
+
+    Ok(Some(segments))
+}
+
+/// Returns `tol` with the point tolerance narrowed to the calculation tolerance.
+fn fine_tolerance(tol: &Tolerance) -> Tolerance {
+    let mut fine = Tolerance::default();
+    fine.set_equal_point(tol.calculation());
+    fine.set_equal_vector(tol.equal_vector());
+    fine.set_convergnece(tol.convergence());
+    fine.set_calculation(tol.calculation());
+
+    fine
+}
+
+/// Makes the arc leaving `from` along the tangent, or the line if `to` lies on the tangent.
+fn piece(from: &Point, tangent: &Vector, to: &Point, tol: &Tolerance) -> Result<Segment, BgcError> {
+    match Arc::from_start_tangent_end(from, tangent, to, tol) {
+        Ok(arc) => Ok(Segment::Arc(arc)),
+        Err(BgcError::InvalidInput) => Ok(Segment::Line(Line::new(*from, *to))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Calculates the largest distance from the curve between the parameters to the segments,
+/// or to its start point if there are none.
+fn deviation<C: Curve + ?Sized>(
+    curve: &C,
+    start_param: f64,
+    end_param: f64,
+    segments: &[Segment],
+    tol: &Tolerance
+) -> Result<f64, BgcError> {
+    let start_point = curve.point_at_param(start_param, false, tol)?;
+
+    let mut max_distance: f64 = 0.0;
+    for i in 1..=SAMPLES_PER_SPAN {
+        let param = start_param + (end_param - start_param) * i as f64 / (SAMPLES_PER_SPAN + 1) as f64;
+        let point = curve.point_at_param(param, false, tol)?;
+
+        let mut distance = if segments.is_empty() { point.distance_to(&start_point) } else { f64::MAX };
+        for segment in segments {
+            distance = distance.min(segment.as_curve().distance_to_point(&point, false, tol)?);
+        }
+        max_distance = max_distance.max(distance);
+    }
+
+    Ok(max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that the segments meet end to end with the same tangent.
+    fn assert_g1(spline: &ArcSpline, tol: &Tolerance) {
+        for pair in spline.segments.windows(2) {
+            let (first, second) = (pair[0].as_curve(), pair[1].as_curve());
+            assert!(pair[0].end_point().is_equal_to(&pair[1].start_point(), tol));
+
+            let end = first.tangent_at_param(first.domain().1, false, tol).unwrap();
+            let start = second.tangent_at_param(second.domain().0, false, tol).unwrap();
+            assert!(end.is_equal_to(&start, tol));
+        }
+    }
+
+    #[test]
+    fn arc_spline_of_nurbs() {
+        let tol = Tolerance::default();
+        let curve = NurbsCurve::new(
+            3,
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 3.0, 0.0),
+                Point::new(3.0, -3.0, 0.0),
+                Point::new(4.0, 0.0, 0.0),
+                Point::new(6.0, 2.0, 0.0),
+            ],
+            vec![1.0, 1.0, 2.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        let spline = curve.to_arc_spline(&tol).unwrap();
+        assert!(spline.max_deviation <= tol.equal_point());
+        assert!(spline.segments.iter().any(|s| matches!(s, Segment::Arc(_))));
+        assert!(spline.segments[0].start_point().is_equal_to(&Point::origin(), &tol));
+        assert!(spline.segments.last().unwrap().end_point().is_equal_to(&Point::new(6.0, 2.0, 0.0), &tol));
+        assert_g1(&spline, &tol);
+
+        // The curve stays within the deviation of the segments
+        for i in 0..=50 {
+            let point = curve.point_at_param(i as f64 / 50.0, false, &tol).unwrap();
+            let distance = spline.segments
+                .iter()
+                .map(|s| s.as_curve().distance_to_point(&point, false, &tol).unwrap())
+                .fold(f64::MAX, f64::min);
+            assert!(distance <= tol.equal_point() * 1.1);
+        }
+    }
+
+    #[test]
+    fn arc_spline_of_exact_shapes() {
+        let tol = Tolerance::default();
+
+        // A rational quarter circle is matched by a single biarc of the same circle
+        let quarter = NurbsCurve::new(
+            2,
+            vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0)],
+            vec![1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+        let spline = quarter.to_arc_spline(&tol).unwrap();
+        assert_eq!(spline.segments.len(), 2);
+        assert!(spline.max_deviation < tol.calculation());
+        for segment in &spline.segments {
+            let Segment::Arc(arc) = segment else { panic!("expected an arc") };
+            assert!(arc.center_point.is_equal_to(&Point::origin(), &tol));
+            assert!((arc.radius - 1.0).abs() < tol.calculation());
+        }
+        assert_g1(&spline, &tol);
+
+        let line = Line::new(Point::new(1.0, 2.0, 3.0), Point::new(4.0, 6.0, 3.0));
+        let spline = line.to_arc_spline(&tol).unwrap();
+        assert_eq!(spline.segments.len(), 1);
+        assert!(matches!(spline.segments[0], Segment::Line(_)));
+
+        // The ends of a full circle meet, but it is not a point
+        let circle = Circle::new(&Point::origin(), &Vector::z_axis(), 1.0, &tol).unwrap();
+        let spline = circle.to_arc_spline(&tol).unwrap();
+        assert!(spline.segments.len() >= 2);
+        assert!(spline.max_deviation <= tol.equal_point());
+
+        // A line shorter than the tolerance is kept as it is
+        let short = Line::new(Point::origin(), Point::new(5.0e-5, 0.0, 0.0));
+        let spline = short.to_arc_spline(&tol).unwrap();
+        assert_eq!(spline.segments.len(), 1);
+        assert!(spline.segments[0].end_point().is_equal_to(&short.end_point, &tol));
+    }
+
+    #[test]
+    fn arc_spline_near_cusp() {
+        let mut tol = Tolerance::default();
+        let curve = NurbsCurve::new(
+            3,
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 1.0, 0.0),
+                Point::new(0.001, 1.0, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+            ],
+            vec![1.0, 1.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        // The sharp turn is bisected many times into short, nearly straight pieces
+        tol.set_equal_point(1.0e-5);
+        let spline = curve.to_arc_spline(&tol).unwrap();
+        assert!(spline.segments.len() > 64);
+        assert!(spline.max_deviation <= tol.equal_point());
+        assert_g1(&spline, &tol);
+    }
+
+    #[test]
+    fn arc_spline_out_of_tolerance() {
+        let mut tol = Tolerance::default();
+        let curve = NurbsCurve::new(
+            3,
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 3.0, 0.0),
+                Point::new(3.0, -3.0, 0.0),
+                Point::new(4.0, 0.0, 0.0),
+            ],
+            vec![1.0, 1.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        // No deviation is small enough to be exact, so the subdivision gives up
+        tol.set_equal_point(0.0);
+        assert_eq!(curve.to_arc_spline(&tol).unwrap_err(), BgcError::Deivergence);
+    }
+}
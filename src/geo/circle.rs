@@ -0,0 +1,255 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// A full circle.
+///
+/// The parameter is the angle in [0, 2π) in the local coordinate system.
+#[derive(Debug, Clone, Copy)]
+pub struct Circle {
+    pub center_point: Point,
+    pub x_axis: Vector,
+    pub y_axis: Vector,
+    pub radius: f64,
+}
+
+impl Circle {
+    /// Makes a circle from its center, normal and radius.
+    ///
+    /// The x axis is chosen by the arbitrary axis algorithm of DXF.
+    pub fn new(center: &Point, normal: &Vector, radius: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        let arc = Arc::from_center_radius_angles(center, normal, radius, 0.0, std::f64::consts::PI * 2.0, tol)?;
+
+        Ok(Self::from_arc(&arc))
+    }
+
+    /// Makes a circle through three points.
+    pub fn from_three_points(
+        point1: &Point,
+        point2: &Point,
+        point3: &Point,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        Ok(Self::from_arc(&Arc::from_three_points(point1, point3, point2, tol)?))
+    }
+
+    /// Returns the circle on which input arc lies.
+    pub fn from_arc(arc: &Arc) -> Self {
+        Self { center_point: arc.center_point,
+               x_axis: arc.x_axis,
+               y_axis: arc.y_axis,
+               radius: arc.radius }
+    }
+
+    /// Returns the arc from 0 to 2π on the frame of this circle.
+    pub fn to_arc(&self) -> Arc {
+        Arc { center_point: self.center_point,
+              x_axis: self.x_axis,
+              y_axis: self.y_axis,
+              radius: self.radius,
+              start_angle: 0.0,
+              end_angle: std::f64::consts::PI * 2.0 }
+    }
+
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    pub fn circumference(&self) -> f64 {
+        std::f64::consts::PI * 2.0 * self.radius
+    }
+
+    pub fn normal(&self, tol: &Tolerance) -> Vector {
+        self.x_axis.outer_product(&self.y_axis).normal(tol)
+    }
+
+    pub fn containing_plane(&self, tol: &Tolerance) -> Plane {
+        self.to_arc().containing_plane(tol)
+    }
+
+    /// Calculates the closest point on this circle to input point.
+    pub fn closest_point(&self, point: &Point, tol: &Tolerance) -> Result<Point, BgcError> {
+        self.to_arc().closest_point(point, true, tol)
+    }
+
+    /// Determines if input point lies on this circle.
+    pub fn contains(&self, point: &Point, tol: &Tolerance) -> bool {
+        self.to_arc().contains(point, true, tol)
+    }
+
+    /// Determines if input point lies on the disk bounded by this circle.
+    pub fn encloses(&self, point: &Point, tol: &Tolerance) -> bool {
+        let plane = self.containing_plane(tol);
+
+        plane.contains(point, tol)
+            && self.center_point.distance_to(point) <= self.radius + tol.equal_point()
+    }
+
+    /// Calculates the intersection points of input circle and this circle.
+    pub fn intersect_with_circle(&self, other: &Self, tol: &Tolerance) -> Result<Vec<Point>, BgcError> {
+        self.to_arc().intersect_with_arc(&other.to_arc(), false, tol)
+    }
+
+    pub fn transform(&self, mat: &Matrix3d, tol: &Tolerance) -> Result<Self, BgcError> {
+        Ok(Self::from_arc(&self.to_arc().transform(mat, tol)?))
+    }
+}
+
+impl Curve for Circle {
+    fn domain(&self) -> (f64, f64) {
+        (0.0, std::f64::consts::PI * 2.0)
+    }
+
+    fn point_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Point, BgcError> {
+        // Every angle is on the circle
+        self.to_arc().point_at_param(param.rem_euclid(std::f64::consts::PI * 2.0), extends, tol)
+    }
+
+    fn derivatives_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<(Vector, Vector), BgcError> {
+        // Every angle is on the circle
+        self.to_arc().derivatives_at_param(param.rem_euclid(std::f64::consts::PI * 2.0), extends, tol)
+    }
+
+    fn param_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        self.to_arc().param_at_point(point, extends, tol)
+    }
+
+    fn length_between(
+        &self,
+        start_param: f64,
+        end_param: f64,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        self.to_arc().length_between(start_param, end_param, tol)
+    }
+
+    fn param_at_length(&self, length: f64, tol: &Tolerance) -> Result<f64, BgcError> {
+        self.to_arc().param_at_length(length, tol)
+    }
+
+    fn intersect_with_line(
+        &self,
+        other: &Line,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        self.to_arc().intersect_with_line(other, extends, tol)
+    }
+
+    fn intersect_with_arc(
+        &self,
+        other: &Arc,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        self.to_arc().intersect_with_arc(other, extends, tol)
+    }
+
+    fn intersect_with_plane(
+        &self,
+        other: &Plane,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        self.to_arc().intersect_with_plane(other, extends, tol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_measures() {
+        let tol = Tolerance::default();
+        let circle = Circle::new(&Point::new(1.0, 2.0, 3.0), &Vector::z_axis(), 2.0, &tol).unwrap();
+
+        assert!((circle.area() - std::f64::consts::PI * 4.0).abs() < tol.calculation());
+        assert!((circle.circumference() - std::f64::consts::PI * 4.0).abs() < tol.calculation());
+        let half = circle.length_between(0.0, std::f64::consts::PI, &tol).unwrap();
+        assert!((half - circle.circumference() / 2.0).abs() < tol.calculation());
+        assert!(circle.normal(&tol).is_equal_to(&Vector::z_axis(), &tol));
+
+        // Angles past a full turn are still on the circle
+        let point = circle.point_at_param(-std::f64::consts::FRAC_PI_2, false, &tol).unwrap();
+        assert!(point.is_equal_to(&Point::new(1.0, 0.0, 3.0), &tol));
+        let (d1, _) = circle.derivatives_at_param(std::f64::consts::PI * 5.0, false, &tol).unwrap();
+        assert!(d1.is_equal_to(&Vector::new(0.0, -2.0, 0.0), &tol));
+
+        assert_eq!(
+            Circle::new(&Point::origin(), &Vector::z_axis(), 0.0, &tol).unwrap_err(),
+            BgcError::MustBePositive
+        );
+    }
+
+    #[test]
+    fn circle_closest_point_and_containment() {
+        let tol = Tolerance::default();
+        let circle = Circle::from_three_points(
+            &Point::new(1.0, 0.0, 0.0),
+            &Point::new(0.0, 1.0, 0.0),
+            &Point::new(-1.0, 0.0, 0.0),
+            &tol
+        ).unwrap();
+        assert!(circle.center_point.is_equal_to(&Point::origin(), &tol));
+        assert!((circle.radius - 1.0).abs() < tol.calculation());
+
+        let closest = circle.closest_point(&Point::new(0.0, -3.0, 2.0), &tol).unwrap();
+        assert!(closest.is_equal_to(&Point::new(0.0, -1.0, 0.0), &tol));
+
+        // The seam is no special point
+        let near_seam = Point::new((-0.001_f64).cos(), (-0.001_f64).sin(), 0.0);
+        assert!(circle.contains(&near_seam, &tol));
+        assert!(circle.contains(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!((circle.param_at_point(&near_seam, false, &tol).unwrap() - (std::f64::consts::PI * 2.0 - 0.001)).abs()
+            < tol.calculation());
+
+        assert!(!circle.contains(&Point::new(0.5, 0.0, 0.0), &tol));
+        assert!(circle.encloses(&Point::new(0.5, 0.0, 0.0), &tol));
+        assert!(!circle.encloses(&Point::new(0.5, 0.0, 1.0), &tol));
+        assert!(!circle.encloses(&Point::new(1.5, 0.0, 0.0), &tol));
+    }
+
+    #[test]
+    fn circle_intersections() {
+        let tol = Tolerance::default();
+        let circle = Circle::new(&Point::origin(), &Vector::z_axis(), 2.0, &tol).unwrap();
+
+        let line = Line::new(Point::new(-3.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0));
+        let points = circle.intersect_with_line(&line, false, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().any(|p| p.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol)));
+        assert!(points.iter().any(|p| p.is_equal_to(&Point::new(-2.0, 0.0, 0.0), &tol)));
+        assert_eq!(line.intersect_with(&circle, false, &tol).unwrap().len(), 2);
+
+        let other = Circle::new(&Point::new(2.0, 0.0, 0.0), &Vector::z_axis(), 2.0, &tol).unwrap();
+        let points = circle.intersect_with_circle(&other, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().any(|p| p.is_equal_to(&Point::new(1.0, 3.0_f64.sqrt(), 0.0), &tol)));
+        assert!(points.iter().any(|p| p.is_equal_to(&Point::new(1.0, -(3.0_f64.sqrt()), 0.0), &tol)));
+
+        let arc = Arc { end_angle: std::f64::consts::PI, ..other.to_arc() };
+        let points = circle.intersect_with_arc(&arc, false, &tol).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].is_equal_to(&Point::new(1.0, 3.0_f64.sqrt(), 0.0), &tol));
+
+        let plane = Plane::from(&Point::new(1.0, 0.0, 0.0), &Vector::x_axis(), &tol);
+        let points = circle.intersect_with_plane(&plane, false, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|p| (p.x - 1.0).abs() < tol.equal_point() && circle.contains(p, &tol)));
+    }
+}
@@ -0,0 +1,240 @@
+mod fillet;
+mod chamfer;
+mod tangent;
+mod tangent_circle;
+
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+pub use fillet::{ fillet, Fillet };
+pub use chamfer::{ chamfer, chamfer_by_angle, Chamfer };
+pub use tangent::{ tangents_from_point, external_tangents, internal_tangents };
+pub use tangent_circle::{ circles_tangent_to_two, circles_tangent_to_three, TangentCircle };
+
+/// Makes the line from input point to its foot on `line`.
+///
+/// # Returns
+///
+/// * `Err(BgcError::InvalidInput)` - `line` has no length, or the point lies on it
+pub fn perpendicular_from_point(point: &Point, line: &Line, tol: &Tolerance) -> Result<Line, BgcError> {
+    if line.length() < tol.equal_point() {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let foot = line.closest_point(point, true, tol);
+    if foot.is_equal_to(point, tol) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    Ok(Line::new(*point, foot))
+}
+
+/// Makes the line parallel to `line` with the same length and direction, starting at
+/// input point.
+pub fn parallel_through_point(line: &Line, point: &Point, tol: &Tolerance) -> Result<Line, BgcError> {
+    if line.length() < tol.equal_point() {
+        return Err(BgcError::InvalidInput);
+    }
+
+    Ok(Line::new(*point, *point + (line.end_point - line.start_point)))
+}
+
+/// Makes the bisector of the angle between the directions of two lines.
+///
+/// The bisector passes through the intersection of the extended lines. Reversing one of
+/// the lines gives the bisector of the supplementary angle.
+///
+/// # Returns
+///
+/// * `Err(BgcError::MustBeNonZero)` - the lines are parallel
+/// * `Err(BgcError::InvalidInput)` - the lines do not meet
+pub fn angle_bisector(line1: &Line, line2: &Line, tol: &Tolerance) -> Result<InfiniteLine, BgcError> {
+    let intersection = line1.intersect_with_line_params(line2, true, tol)?;
+
+    InfiniteLine::new(intersection.point, &(line1.direction(tol) + line2.direction(tol)), tol)
+}
+
+/// Makes the perpendicular bisector of a segment on the plane.
+///
+/// The segment must be parallel to the plane. The direction of the bisector is the left
+/// of the segment seen from the normal of the plane.
+pub fn perpendicular_bisector(line: &Line, plane: &Plane, tol: &Tolerance) -> Result<InfiniteLine, BgcError> {
+    if line.length() < tol.equal_point() || !line.is_parallel_with_plane(plane, tol) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let middle = line.start_point + (line.end_point - line.start_point) * 0.5;
+    let left = plane.get_normal_vector(tol).outer_product(&line.direction(tol));
+
+    InfiniteLine::new(middle, &left, tol)
+}
+
+/// Makes the tangent line of a curve at a parameter, directed along the curve.
+///
+/// # Returns
+///
+/// * `Err(BgcError::MustBeNonZero)` - the derivative vanishes at the parameter
+pub fn tangent_line<T>(curve: &T, param: f64, tol: &Tolerance) -> Result<InfiniteLine, BgcError>
+where
+    T: Curve + ?Sized
+{
+    let point = curve.point_at_param(param, false, tol)?;
+    let (d1, _) = curve.derivatives_at_param(param, false, tol)?;
+
+    InfiniteLine::new(point, &d1, tol)
+}
+
+/// Makes the normal line of a curve at a parameter on the plane.
+///
+/// The tangent at the parameter must be parallel to the plane. The normal is directed to
+/// the left of the curve seen from the normal of the plane.
+pub fn normal_line<T>(curve: &T, param: f64, plane: &Plane, tol: &Tolerance) -> Result<InfiniteLine, BgcError>
+where
+    T: Curve + ?Sized
+{
+    let tangent = tangent_line(curve, param, tol)?;
+
+    let normal = plane.get_normal_vector(tol);
+    if normal.inner_product(&tangent.direction).abs() >= tol.equal_vector() {
+        return Err(BgcError::InvalidInput);
+    }
+
+    InfiniteLine::new(tangent.point, &normal.outer_product(&tangent.direction), tol)
+}
+
+/// Returns the curves on the plane at `distance` from the extension of the segment.
+fn offsets(segment: &Segment, distance: f64, plane: &Plane, tol: &Tolerance) -> Result<Vec<Segment>, BgcError> {
+    match segment {
+        Segment::Line(line) => Ok(vec![
+            Segment::Line(line.offset(distance, plane, tol)?),
+            Segment::Line(line.offset(-distance, plane, tol)?),
+        ]),
+        Segment::Arc(arc) => {
+            let circle = |radius: f64| Segment::Arc(Arc { center_point: arc.center_point,
+                                                          x_axis: arc.x_axis,
+                                                          y_axis: arc.y_axis,
+                                                          radius,
+                                                          start_angle: 0.0,
+                                                          end_angle: std::f64::consts::PI * 2.0 });
+
+            // The inner offset encloses the arc when the distance exceeds its radius
+            let mut circles = vec![circle(arc.radius + distance)];
+            if (arc.radius - distance).abs() > tol.equal_point() {
+                circles.push(circle((arc.radius - distance).abs()));
+            }
+
+            Ok(circles)
+        },
+    }
+}
+
+/// Calculates the tangent point of the circle on the extension of the segment, if they touch.
+///
+/// On an arc it is the nearer or the farther point from the center, whichever is at
+/// `radius` from it.
+fn tangent_point(segment: &Segment, center: &Point, radius: f64, tol: &Tolerance) -> Result<Option<Point>, BgcError> {
+    let candidates = match segment {
+        Segment::Line(line) => vec![line.closest_point(center, true, tol)],
+        Segment::Arc(arc) => {
+            if arc.center_point.is_equal_to(center, tol) {
+                return Ok(None);
+            }
+            let u = (center - arc.center_point).normal(tol);
+            vec![arc.center_point + u * arc.radius, arc.center_point - u * arc.radius]
+        },
+    };
+
+    Ok(candidates.into_iter().find(|p| (p.distance_to(center) - radius).abs() < tol.equal_point()))
+}
+
+/// Calculates the intersection points of two extended segments, none where they do not meet.
+fn intersect_extended(segment1: &Segment, segment2: &Segment, tol: &Tolerance) -> Vec<Point> {
+    let intersections = match segment1 {
+        Segment::Line(line) => segment2.as_curve().intersect_with_line(line, true, tol),
+        Segment::Arc(arc) => segment2.as_curve().intersect_with_arc(arc, true, tol),
+    };
+
+    intersections.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construct_perpendicular_and_parallel() {
+        let tol = Tolerance::default();
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0));
+
+        let perp = perpendicular_from_point(&Point::new(6.0, 3.0, 1.0), &line, &tol).unwrap();
+        assert!(perp.start_point.is_equal_to(&Point::new(6.0, 3.0, 1.0), &tol));
+        assert!(perp.end_point.is_equal_to(&Point::new(6.0, 0.0, 0.0), &tol));
+        assert!(perp.direction(&tol).inner_product(&line.direction(&tol)).abs() < tol.calculation());
+
+        assert_eq!(
+            perpendicular_from_point(&Point::new(2.0, 0.0, 0.0), &line, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+
+        let parallel = parallel_through_point(&line, &Point::new(1.0, 2.0, 0.0), &tol).unwrap();
+        assert!(parallel.start_point.is_equal_to(&Point::new(1.0, 2.0, 0.0), &tol));
+        assert!(parallel.end_point.is_equal_to(&Point::new(5.0, 2.0, 0.0), &tol));
+        assert!(parallel.is_parallel(&line, &tol));
+    }
+
+    #[test]
+    fn construct_bisectors() {
+        let tol = Tolerance::default();
+        let l1 = Line::new(Point::new(1.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0));
+        let l2 = Line::new(Point::new(0.0, 1.0, 0.0), Point::new(0.0, 3.0, 0.0));
+
+        let bisector = angle_bisector(&l1, &l2, &tol).unwrap();
+        assert!(bisector.point.is_equal_to(&Point::origin(), &tol));
+        let diagonal = Vector::new(1.0, 1.0, 0.0).normal(&tol);
+        assert!(bisector.direction.is_equal_to(&diagonal, &tol));
+
+        let other = angle_bisector(&l1.reverse(), &l2, &tol).unwrap();
+        assert!(other.direction.is_equal_to(&Vector::new(-1.0, 1.0, 0.0).normal(&tol), &tol));
+
+        let parallel = Line::new(Point::new(0.0, 1.0, 0.0), Point::new(2.0, 1.0, 0.0));
+        assert_eq!(angle_bisector(&l1, &parallel, &tol).unwrap_err(), BgcError::MustBeNonZero);
+
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+        let bisector = perpendicular_bisector(&l1, &plane, &tol).unwrap();
+        assert!(bisector.point.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(bisector.direction.is_equal_to(&Vector::y_axis(), &tol));
+
+        let vertical = Line::new(Point::origin(), Point::new(0.0, 0.0, 1.0));
+        assert_eq!(perpendicular_bisector(&vertical, &plane, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn construct_tangent_and_normal() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+
+        let tangent = tangent_line(&arc, std::f64::consts::FRAC_PI_2, &tol).unwrap();
+        assert!(tangent.point.is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+        assert!(tangent.direction.is_equal_to(&(Vector::x_axis() * -1.0), &tol));
+
+        // The left of a counterclockwise arc points to its center
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+        let normal = normal_line(&arc, std::f64::consts::FRAC_PI_2, &plane, &tol).unwrap();
+        assert!(normal.direction.is_equal_to(&(Vector::y_axis() * -1.0), &tol));
+        assert!(normal.contains(&arc.center_point, &tol));
+
+        let side = Plane::from(&Point::origin(), &Vector::x_axis(), &tol);
+        assert_eq!(
+            normal_line(&arc, std::f64::consts::FRAC_PI_2, &side, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+        assert_eq!(tangent_line(&arc, 4.0, &tol).unwrap_err(), BgcError::OutOfRange);
+    }
+}
@@ -0,0 +1,153 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// A chamfer line with both lines trimmed to its ends.
+#[derive(Debug)]
+pub struct Chamfer {
+    /// Runs from the end on `line1` to the end on `line2`.
+    pub line: Line,
+    pub line1: Line,
+    pub line2: Line,
+}
+
+/// Makes the chamfer cutting `distance1` from `line1` and `distance2` from `line2`.
+///
+/// The distances are measured from the corner where the extended lines meet, towards the
+/// end of each line farther from the corner. That part of each line is kept.
+///
+/// # Returns
+///
+/// * `Err(BgcError::MustBePositive)` - a distance is not positive
+/// * `Err(BgcError::MustBeNonZero)` - the lines are parallel
+/// * `Err(BgcError::InvalidInput)` - the lines do not meet
+/// * `Err(BgcError::OutOfRange)` - nothing of a line would remain
+pub fn chamfer(
+    line1: &Line,
+    line2: &Line,
+    distance1: f64,
+    distance2: f64,
+    tol: &Tolerance
+) -> Result<Chamfer, BgcError> {
+    if distance1 < tol.equal_point() || distance2 < tol.equal_point() {
+        return Err(BgcError::MustBePositive);
+    }
+
+    let corner = corner_of(line1, line2, tol)?;
+    let (end1, trimmed1) = cut(line1, &corner, distance1, tol)?;
+    let (end2, trimmed2) = cut(line2, &corner, distance2, tol)?;
+
+    Ok(Chamfer { line: Line::new(end1, end2), line1: trimmed1, line2: trimmed2 })
+}
+
+/// Makes the chamfer cutting `distance` from `line1` at `angle` to `line1`.
+///
+/// a = the angle between the lines at the corner <br>
+/// distance2 = distance\*sin(angle)/sin(a + angle)
+///
+/// # Returns
+///
+/// * `Err(BgcError::OutOfRange)` - the chamfer does not meet `line2` at the angle, or
+///   nothing of a line would remain
+pub fn chamfer_by_angle(
+    line1: &Line,
+    line2: &Line,
+    distance: f64,
+    angle: f64,
+    tol: &Tolerance
+) -> Result<Chamfer, BgcError> {
+    if distance < tol.equal_point() {
+        return Err(BgcError::MustBePositive);
+    }
+
+    let corner = corner_of(line1, line2, tol)?;
+    let corner_angle = away_from(line1, &corner, tol).angle_to(&away_from(line2, &corner, tol), tol);
+    if angle < tol.calculation() || std::f64::consts::PI - tol.calculation() < corner_angle + angle {
+        return Err(BgcError::OutOfRange);
+    }
+
+    chamfer(line1, line2, distance, distance * angle.sin() / (corner_angle + angle).sin(), tol)
+}
+
+fn corner_of(line1: &Line, line2: &Line, tol: &Tolerance) -> Result<Point, BgcError> {
+    let points = line1.intersect_with_line(line2, true, tol)?;
+
+    points.into_iter().next().ok_or(BgcError::InvalidInput)
+}
+
+/// Returns the end of the line farther from the corner.
+fn far_end(line: &Line, corner: &Point) -> Point {
+    if line.start_point.distance_to(corner) >= line.end_point.distance_to(corner) {
+        line.start_point
+    } else {
+        line.end_point
+    }
+}
+
+fn away_from(line: &Line, corner: &Point, tol: &Tolerance) -> Vector {
+    (far_end(line, corner) - corner).normal(tol)
+}
+
+/// Cuts the line at `distance` from the corner, keeping the part away from the corner.
+fn cut(line: &Line, corner: &Point, distance: f64, tol: &Tolerance) -> Result<(Point, Line), BgcError> {
+    let far = far_end(line, corner);
+    if far.distance_to(corner) - distance < tol.equal_point() {
+        return Err(BgcError::OutOfRange);
+    }
+
+    let point = *corner + (far - corner).normal(tol) * distance;
+    let trimmed = if far.is_equal_to(&line.start_point, tol) {
+        Line::new(line.start_point, point)
+    } else {
+        Line::new(point, line.end_point)
+    };
+
+    Ok((point, trimmed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chamfer_by_distances() {
+        let tol = Tolerance::default();
+        let line1 = Line::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0));
+        let line2 = Line::new(Point::new(0.0, 1.0, 0.0), Point::new(0.0, 10.0, 0.0));
+
+        let result = chamfer(&line1, &line2, 2.0, 3.0, &tol).unwrap();
+        assert!(result.line.start_point.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(result.line.end_point.is_equal_to(&Point::new(0.0, 3.0, 0.0), &tol));
+        assert!(result.line1.start_point.is_equal_to(&line1.start_point, &tol));
+        assert!(result.line1.end_point.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(result.line2.start_point.is_equal_to(&Point::new(0.0, 3.0, 0.0), &tol));
+        assert!(result.line2.end_point.is_equal_to(&line2.end_point, &tol));
+
+        assert_eq!(chamfer(&line1, &line2, 11.0, 1.0, &tol).unwrap_err(), BgcError::OutOfRange);
+        assert_eq!(chamfer(&line1, &line2, 0.0, 1.0, &tol).unwrap_err(), BgcError::MustBePositive);
+
+        let parallel = Line::new(Point::new(0.0, 1.0, 0.0), Point::new(10.0, 1.0, 0.0));
+        assert_eq!(chamfer(&line1, &parallel, 1.0, 1.0, &tol).unwrap_err(), BgcError::MustBeNonZero);
+    }
+
+    #[test]
+    fn chamfer_by_distance_and_angle() {
+        let tol = Tolerance::default();
+        let line1 = Line::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0));
+        let line2 = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+
+        // 45 degrees on a right angle cuts equally
+        let result = chamfer_by_angle(&line1, &line2, 2.0, std::f64::consts::FRAC_PI_4, &tol).unwrap();
+        assert!(result.line.start_point.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(result.line.end_point.is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+
+        let result = chamfer_by_angle(&line1, &line2, 2.0, std::f64::consts::PI / 3.0, &tol).unwrap();
+        assert!(result.line.end_point.is_equal_to(&Point::new(0.0, 2.0 * 3.0_f64.sqrt(), 0.0), &tol));
+        let angle = (result.line.start_point - result.line.end_point).angle_to(&Vector::x_axis(), &tol);
+        assert!((angle - std::f64::consts::PI / 3.0).abs() < tol.calculation());
+
+        assert_eq!(
+            chamfer_by_angle(&line1, &line2, 2.0, std::f64::consts::FRAC_PI_2, &tol).unwrap_err(),
+            BgcError::OutOfRange
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// A fillet arc with both curves trimmed to its tangent points.
+#[derive(Debug)]
+pub struct Fillet {
+    /// Runs from the tangent point on `curve1` to the tangent point on `curve2`.
+    pub arc: Arc,
+    pub curve1: Segment,
+    pub curve2: Segment,
+}
+
+/// Makes the fillet of `radius` between two curves on the plane.
+///
+/// Of the circles tangent to both extended curves, the one whose center is the nearest to
+/// `pick` is used. Each curve is trimmed, or extended, to its tangent point on the side
+/// away from the fillet.
+///
+/// # Returns
+///
+/// * `Err(BgcError::MustBePositive)` - `radius` is not positive
+/// * `Err(BgcError::InvalidInput)` - a curve is not on the plane, no fillet exists, or
+///   nothing of a curve would remain
+pub fn fillet(
+    curve1: &Segment,
+    curve2: &Segment,
+    radius: f64,
+    pick: &Point,
+    plane: &Plane,
+    tol: &Tolerance
+) -> Result<Fillet, BgcError> {
+    if radius < tol.equal_point() {
+        return Err(BgcError::MustBePositive);
+    }
+    if !curve1.lies_on(plane, tol) || !curve2.lies_on(plane, tol) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let mut best: Option<(f64, Point, Point, Point)> = None;
+    for offset1 in offsets(curve1, radius, plane, tol)? {
+        for offset2 in offsets(curve2, radius, plane, tol)? {
+            for center in intersect_extended(&offset1, &offset2, tol) {
+                let (Some(tangent1), Some(tangent2)) = (
+                    tangent_point(curve1, &center, radius, tol)?,
+                    tangent_point(curve2, &center, radius, tol)?,
+                ) else {
+                    continue;
+                };
+                if tangent1.is_equal_to(&tangent2, tol) {
+                    continue;
+                }
+
+                let distance = center.distance_to(pick);
+                if best.is_none_or(|b| distance < b.0) {
+                    best = Some((distance, center, tangent1, tangent2));
+                }
+            }
+        }
+    }
+    let (_, center, tangent1, tangent2) = best.ok_or(BgcError::InvalidInput)?;
+
+    let normal = plane.get_normal_vector(tol);
+    let direction = if (tangent1 - center).outer_product(&(tangent2 - center)).inner_product(&normal) > 0.0 {
+        SweepDirection::CounterClockwise
+    } else {
+        SweepDirection::Clockwise
+    };
+    let arc = Arc::from_center_start_end(&center, &tangent1, &tangent2, &normal, direction, tol)?;
+
+    Ok(Fillet { arc,
+                curve1: trim_to_tangent(curve1, &tangent1, &tangent2, tol)?,
+                curve2: trim_to_tangent(curve2, &tangent2, &tangent1, tol)? })
+}
+
+/// Moves the end of the segment on the side of the fillet to the tangent point.
+fn trim_to_tangent(segment: &Segment, tangent: &Point, other: &Point, tol: &Tolerance) -> Result<Segment, BgcError> {
+    let curve = segment.as_curve();
+    let param = curve.param_at_point(tangent, true, tol)?;
+    let (d1, _) = curve.derivatives_at_param(param, true, tol)?;
+
+    // The fillet leaves the tangent point forward if it continues the curve
+    let end = if d1.inner_product(&(other - tangent)) > 0.0 { CurveEnd::End } else { CurveEnd::Start };
+
+    segment.move_end_to(end, tangent, tol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xy_plane(tol: &Tolerance) -> Plane {
+        Plane::from(&Point::origin(), &Vector::z_axis(), tol)
+    }
+
+    #[test]
+    fn fillet_of_lines() {
+        let tol = Tolerance::default();
+        let plane = xy_plane(&tol);
+
+        // A corner at the origin
+        let line1 = Segment::Line(Line::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0)));
+        let line2 = Segment::Line(Line::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0)));
+
+        let result = fillet(&line1, &line2, 2.0, &Point::new(1.0, 1.0, 0.0), &plane, &tol).unwrap();
+        assert!(result.arc.center_point.is_equal_to(&Point::new(2.0, 2.0, 0.0), &tol));
+        assert!(result.arc.start_point().is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(result.arc.end_point().is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+        assert!((result.arc.length() - std::f64::consts::PI).abs() < tol.calculation());
+        assert!(result.curve1.start_point().is_equal_to(&Point::new(10.0, 0.0, 0.0), &tol));
+        assert!(result.curve1.end_point().is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(result.curve2.start_point().is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+        assert!(result.curve2.end_point().is_equal_to(&Point::new(0.0, 10.0, 0.0), &tol));
+
+        // Crossing lines, the pick chooses the quadrant
+        let line1 = Segment::Line(Line::new(Point::new(-10.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)));
+        let line2 = Segment::Line(Line::new(Point::new(0.0, -10.0, 0.0), Point::new(0.0, 10.0, 0.0)));
+        let result = fillet(&line1, &line2, 1.0, &Point::new(-3.0, 3.0, 0.0), &plane, &tol).unwrap();
+        assert!(result.arc.center_point.is_equal_to(&Point::new(-1.0, 1.0, 0.0), &tol));
+        assert!(result.curve1.start_point().is_equal_to(&Point::new(-10.0, 0.0, 0.0), &tol));
+        assert!(result.curve1.end_point().is_equal_to(&Point::new(-1.0, 0.0, 0.0), &tol));
+        assert!(result.curve2.start_point().is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        assert!(result.curve2.end_point().is_equal_to(&Point::new(0.0, 10.0, 0.0), &tol));
+
+        // Lines short of the corner are extended
+        let line1 = Segment::Line(Line::new(Point::new(10.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0)));
+        let line2 = Segment::Line(Line::new(Point::new(0.0, 5.0, 0.0), Point::new(0.0, 10.0, 0.0)));
+        let result = fillet(&line1, &line2, 1.0, &Point::new(1.0, 1.0, 0.0), &plane, &tol).unwrap();
+        assert!(result.curve1.end_point().is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!(result.curve2.start_point().is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+
+        let parallel = Segment::Line(Line::new(Point::new(0.0, 1.0, 0.0), Point::new(10.0, 1.0, 0.0)));
+        assert_eq!(
+            fillet(&line1, &parallel, 1.0, &Point::origin(), &plane, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+        assert_eq!(
+            fillet(&line1, &line2, 0.0, &Point::origin(), &plane, &tol).unwrap_err(),
+            BgcError::MustBePositive
+        );
+    }
+
+    #[test]
+    fn fillet_of_line_and_arc() {
+        let tol = Tolerance::default();
+        let plane = xy_plane(&tol);
+
+        // A line leaving the top of a circle towards +X
+        let arc = Segment::Arc(Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        });
+        let line = Segment::Line(Line::new(Point::new(-5.0, 1.0, 0.0), Point::new(5.0, 1.0, 0.0)));
+
+        let result = fillet(&arc, &line, 1.0, &Point::new(2.5, 1.5, 0.0), &plane, &tol).unwrap();
+        let center = Point::new(5.0_f64.sqrt(), 2.0, 0.0);
+        assert!(result.arc.center_point.is_equal_to(&center, &tol));
+
+        let tangent1 = Point::origin() + (center - Point::origin()).normal(&tol) * 2.0;
+        let tangent2 = Point::new(center.x, 1.0, 0.0);
+        assert!(result.arc.start_point().is_equal_to(&tangent1, &tol));
+        assert!(result.arc.end_point().is_equal_to(&tangent2, &tol));
+
+        // The arc keeps its part up from the tangent point, the line its part to the right
+        assert!(result.curve1.start_point().is_equal_to(&tangent1, &tol));
+        assert!(result.curve1.end_point().is_equal_to(&Point::new(-2.0, 0.0, 0.0), &tol));
+        assert!(result.curve2.start_point().is_equal_to(&tangent2, &tol));
+        assert!(result.curve2.end_point().is_equal_to(&Point::new(5.0, 1.0, 0.0), &tol));
+
+        // Both curves are tangent to the fillet
+        let (d1, _) = result.arc.derivatives_at_param(result.arc.domain().1, false, &tol).unwrap();
+        assert!(d1.normal(&tol).is_equal_to(&Vector::x_axis(), &tol));
+    }
+
+    #[test]
+    fn fillet_of_arcs() {
+        let tol = Tolerance::default();
+        let plane = xy_plane(&tol);
+
+        let arc1 = Segment::Arc(Arc {
+            center_point: Point::new(-3.0, 0.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        });
+        let arc2 = Segment::Arc(Arc {
+            center_point: Point::new(3.0, 0.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        });
+
+        // The fillet outside both arcs above the gap
+        let result = fillet(&arc1, &arc2, 3.0, &Point::new(0.0, 3.0, 0.0), &plane, &tol).unwrap();
+        assert!(result.arc.center_point.is_equal_to(&Point::new(0.0, 4.0, 0.0), &tol));
+        let tangent1 = Point::new(-3.0, 0.0, 0.0) + Vector::new(0.6, 0.8, 0.0) * 2.0;
+        let tangent2 = Point::new(3.0, 0.0, 0.0) + Vector::new(-0.6, 0.8, 0.0) * 2.0;
+        assert!(result.arc.start_point().is_equal_to(&tangent1, &tol));
+        assert!(result.arc.end_point().is_equal_to(&tangent2, &tol));
+        assert!(result.curve1.end_point().is_equal_to(&Point::new(-5.0, 0.0, 0.0), &tol));
+        assert!(result.curve2.start_point().is_equal_to(&Point::new(5.0, 0.0, 0.0), &tol));
+    }
+
+    #[test]
+    fn fillet_enclosing_arc() {
+        let tol = Tolerance::default();
+        let plane = xy_plane(&tol);
+
+        // The fillet radius exceeds the radius of the arc, so the fillet can enclose it
+        let line = Segment::Line(Line::new(Point::new(-10.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0)));
+        let arc = Segment::Arc(Arc {
+            center_point: Point::new(0.0, 2.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: std::f64::consts::PI,
+            end_angle: std::f64::consts::PI * 2.0,
+        });
+
+        let result = fillet(&line, &arc, 3.0, &Point::new(1.0, 1.0, 0.0), &plane, &tol).unwrap();
+        let center = Point::new(3.0_f64.sqrt(), 3.0, 0.0);
+        assert!(result.arc.center_point.is_equal_to(&center, &tol));
+        assert!((result.arc.radius - 3.0).abs() < tol.calculation());
+
+        // The tangent point on the arc is on the far side from the fillet center
+        let tangent2 = Point::new(-3.0_f64.sqrt() / 2.0, 1.5, 0.0);
+        assert!(result.arc.start_point().is_equal_to(&Point::new(3.0_f64.sqrt(), 0.0, 0.0), &tol));
+        assert!(result.arc.end_point().is_equal_to(&tangent2, &tol));
+        assert!(result.curve2.as_curve().distance_to_point(&tangent2, false, &tol).unwrap() < tol.equal_point());
+    }
+}
@@ -0,0 +1,176 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// Makes the tangent lines from input point to the arc.
+///
+/// Each line runs from the point to its tangent point. Tangent points off the arc are
+/// left out, so a full circle from `Circle::to_arc` gives both lines.
+///
+/// u = (P - C)/d, v = N × u <br>
+/// T = C + r(u cos(b) ± v sin(b)), cos(b) = r/d
+///
+/// # Returns
+///
+/// * `Err(BgcError::InvalidInput)` - the point is not on the plane of the arc, or not
+///   outside its circle
+pub fn tangents_from_point(point: &Point, arc: &Arc, tol: &Tolerance) -> Result<Vec<Line>, BgcError> {
+    let plane = arc.containing_plane(tol);
+    if !plane.contains(point, tol) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let distance = arc.center_point.distance_to(point);
+    if distance - arc.radius < tol.equal_point() {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let u = (point - arc.center_point).normal(tol);
+    let v = plane.get_normal_vector(tol).outer_product(&u);
+    let cos_b = arc.radius / distance;
+    let sin_b = (1.0 - cos_b * cos_b).sqrt();
+
+    Ok([1.0, -1.0]
+        .iter()
+        .map(|sign| arc.center_point + (u * cos_b + v * (sin_b * sign)) * arc.radius)
+        .filter(|t| arc.contains(t, false, tol))
+        .map(|t| Line::new(*point, t))
+        .collect())
+}
+
+/// Makes the external common tangent lines of two coplanar arcs.
+///
+/// Both arcs lie on the same side of an external tangent. Each line runs from its tangent
+/// point on `arc1` to its tangent point on `arc2`. Tangent points off the arcs are left out.
+pub fn external_tangents(arc1: &Arc, arc2: &Arc, tol: &Tolerance) -> Result<Vec<Line>, BgcError> {
+    common_tangents(arc1, arc2, 1.0, tol)
+}
+
+/// Makes the internal common tangent lines of two coplanar arcs.
+///
+/// The arcs lie on opposite sides of an internal tangent, which crosses between them.
+/// Each line runs from its tangent point on `arc1` to its tangent point on `arc2`.
+pub fn internal_tangents(arc1: &Arc, arc2: &Arc, tol: &Tolerance) -> Result<Vec<Line>, BgcError> {
+    common_tangents(arc1, arc2, -1.0, tol)
+}
+
+/// Makes the common tangents with the second radius signed by `side`.
+///
+/// u = (C2 - C1)/d, v = N × u <br>
+/// m = u cos(a) ± v sin(a), cos(a) = (r1 - side\*r2)/d <br>
+/// T1 = C1 + r1\*m, T2 = C2 + side\*r2\*m
+///
+/// # Returns
+///
+/// * `Err(BgcError::InvalidInput)` - the arcs are not coplanar, or are concentric
+fn common_tangents(arc1: &Arc, arc2: &Arc, side: f64, tol: &Tolerance) -> Result<Vec<Line>, BgcError> {
+    let plane = arc1.containing_plane(tol);
+    if !plane.is_coplanar_with(&arc2.containing_plane(tol), tol) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let distance = arc1.center_point.distance_to(&arc2.center_point);
+    if distance < tol.equal_point() {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let cos_a = (arc1.radius - side * arc2.radius) / distance;
+    if cos_a.abs() > 1.0 {
+        return Ok(Vec::new());
+    }
+    let sin_a = (1.0 - cos_a * cos_a).sqrt();
+
+    let u = (arc2.center_point - arc1.center_point).normal(tol);
+    let v = plane.get_normal_vector(tol).outer_product(&u);
+
+    let mut lines = Vec::new();
+    for sign in [1.0, -1.0] {
+        let m = u * cos_a + v * (sin_a * sign);
+        let tangent1 = arc1.center_point + m * arc1.radius;
+        let tangent2 = arc2.center_point + m * (side * arc2.radius);
+
+        // Circles touching each other have a single tangent line without length
+        if tangent1.is_equal_to(&tangent2, tol)
+                || lines.iter().any(|l: &Line| l.start_point.is_equal_to(&tangent1, tol)) {
+            continue;
+        }
+
+        if arc1.contains(&tangent1, false, tol) && arc2.contains(&tangent2, false, tol) {
+            lines.push(Line::new(tangent1, tangent2));
+        }
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_tangent(line: &Line, arc: &Arc, point: &Point, tol: &Tolerance) -> bool {
+        (point - arc.center_point).inner_product(&line.direction(tol)).abs() < tol.calculation()
+            && (arc.center_point.distance_to(point) - arc.radius).abs() < tol.calculation()
+    }
+
+    #[test]
+    fn tangents_from_external_point() {
+        let tol = Tolerance::default();
+        let arc = Circle::new(&Point::origin(), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc();
+        let point = Point::new(2.0, 0.0, 0.0);
+
+        let lines = tangents_from_point(&point, &arc, &tol).unwrap();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(line.start_point.is_equal_to(&point, &tol));
+            assert!(is_tangent(line, &arc, &line.end_point, &tol));
+            assert!((line.length() - 3.0_f64.sqrt()).abs() < tol.calculation());
+        }
+        assert!(lines[0].end_point.is_equal_to(&Point::new(0.5, 0.75_f64.sqrt(), 0.0), &tol));
+
+        // Only the upper tangent point is on the half arc
+        let half = Arc { end_angle: std::f64::consts::PI, ..arc };
+        assert_eq!(tangents_from_point(&point, &half, &tol).unwrap().len(), 1);
+
+        assert_eq!(
+            tangents_from_point(&Point::new(0.5, 0.0, 0.0), &arc, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+        assert_eq!(
+            tangents_from_point(&Point::new(2.0, 0.0, 1.0), &arc, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn common_tangents_of_circles() {
+        let tol = Tolerance::default();
+        let small = Circle::new(&Point::origin(), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc();
+        let large = Circle::new(&Point::new(10.0, 0.0, 0.0), &Vector::z_axis(), 3.0, &tol).unwrap().to_arc();
+
+        let lines = external_tangents(&small, &large, &tol).unwrap();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(is_tangent(line, &small, &line.start_point, &tol));
+            assert!(is_tangent(line, &large, &line.end_point, &tol));
+            assert!((line.length() - 96.0_f64.sqrt()).abs() < tol.calculation());
+        }
+
+        let lines = internal_tangents(&small, &large, &tol).unwrap();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(is_tangent(line, &small, &line.start_point, &tol));
+            assert!(is_tangent(line, &large, &line.end_point, &tol));
+            assert!((line.length() - 84.0_f64.sqrt()).abs() < tol.calculation());
+            // The line crosses between the centers
+            assert!(line.start_point.y * line.end_point.y < 0.0);
+        }
+
+        // Overlapping circles have no internal tangents
+        let overlapping = Circle::new(&Point::new(3.0, 0.0, 0.0), &Vector::z_axis(), 3.0, &tol).unwrap().to_arc();
+        assert!(internal_tangents(&small, &overlapping, &tol).unwrap().is_empty());
+        assert_eq!(external_tangents(&small, &overlapping, &tol).unwrap().len(), 2);
+
+        let lifted = Circle::new(&Point::new(10.0, 0.0, 1.0), &Vector::z_axis(), 3.0, &tol).unwrap().to_arc();
+        assert_eq!(external_tangents(&small, &lifted, &tol).unwrap_err(), BgcError::InvalidInput);
+        assert_eq!(external_tangents(&small, &small, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+}
@@ -0,0 +1,391 @@
+use super::*;
+use crate::{ math, BgcError, Tolerance };
+
+/// A circle tangent to given curves with its tangent point on each of them.
+#[derive(Debug)]
+pub struct TangentCircle {
+    pub circle: Circle,
+    /// In the order of the curves.
+    pub tangent_points: Vec<Point>,
+}
+
+/// Makes the circles of `radius` tangent to two curves on the plane (TTR).
+///
+/// The circles are tangent to the extensions of the curves, like the fillets of
+/// `fillet` before trimming.
+///
+/// # Returns
+///
+/// * `Err(BgcError::MustBePositive)` - `radius` is not positive
+/// * `Err(BgcError::InvalidInput)` - a curve is not on the plane
+pub fn circles_tangent_to_two(
+    curve1: &Segment,
+    curve2: &Segment,
+    radius: f64,
+    plane: &Plane,
+    tol: &Tolerance
+) -> Result<Vec<TangentCircle>, BgcError> {
+    if radius < tol.equal_point() {
+        return Err(BgcError::MustBePositive);
+    }
+    if !curve1.lies_on(plane, tol) || !curve2.lies_on(plane, tol) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let mut circles = Vec::new();
+    for offset1 in offsets(curve1, radius, plane, tol)? {
+        for offset2 in offsets(curve2, radius, plane, tol)? {
+            for center in intersect_extended(&offset1, &offset2, tol) {
+                push_solution(&mut circles, &center, radius, &[curve1, curve2], plane, tol)?;
+            }
+        }
+    }
+
+    Ok(circles)
+}
+
+/// Makes the circles tangent to three curves on the plane (TTT, Apollonius' problem).
+///
+/// The circles are tangent to the extensions of the curves. Each combination of sides is
+/// solved in the coordinate system of the plane:
+///
+/// line: a\*x + b\*y + c = s\*r, a^2 + b^2 = 1 <br>
+/// circle: (x - xi)^2 + (y - yi)^2 = (ri + s\*r)^2
+///
+/// The differences of the circle equations are linear, so two linear equations give the
+/// center and radius along a line in (x, y, r) and the remaining equation is quadratic.
+///
+/// # Returns
+///
+/// * `Err(BgcError::InvalidInput)` - a curve is not on the plane
+pub fn circles_tangent_to_three(
+    curve1: &Segment,
+    curve2: &Segment,
+    curve3: &Segment,
+    plane: &Plane,
+    tol: &Tolerance
+) -> Result<Vec<TangentCircle>, BgcError> {
+    let curves = [curve1, curve2, curve3];
+    if curves.iter().any(|c| !c.lies_on(plane, tol)) {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let frame = PlaneFrame::new(curve1, plane, tol);
+    let shapes: Vec<Shape> = curves.iter().map(|c| frame.shape(c, tol)).collect();
+
+    let mut circles = Vec::new();
+    for signs in 0..8 {
+        let sign = |i: usize| if signs & (1 << i) == 0 { 1.0 } else { -1.0 };
+
+        let mut rows = Vec::new();
+        let reference = shapes.iter().position(|s| matches!(s, Shape::Circle(..)));
+        for (i, shape) in shapes.iter().enumerate() {
+            match (shape, reference) {
+                (Shape::Line(a, b, c), _) => rows.push([*a, *b, -sign(i), -c]),
+                (Shape::Circle(..), Some(k)) if k != i => {
+                    // Qi - Qk cancels x^2 + y^2 - r^2
+                    let (qi, qk) = (shapes[i].quadratic(sign(i)), shapes[k].quadratic(sign(k)));
+                    rows.push([qi[0] - qk[0], qi[1] - qk[1], qi[2] - qk[2], qk[3] - qi[3]]);
+                },
+                _ => {},
+            }
+        }
+
+        let solutions = match reference {
+            None => solve_linear(&rows, tol).into_iter().collect(),
+            Some(k) => solve_with_quadratic(&rows, &shapes[k].quadratic(sign(k)), tol),
+        };
+
+        for [x, y, r] in solutions {
+            if r > tol.equal_point() {
+                push_solution(&mut circles, &frame.point(x, y), r, &curves, plane, tol)?;
+            }
+        }
+    }
+
+    Ok(circles)
+}
+
+/// Adds the circle if it is tangent to all curves and not found yet.
+fn push_solution(
+    circles: &mut Vec<TangentCircle>,
+    center: &Point,
+    radius: f64,
+    curves: &[&Segment],
+    plane: &Plane,
+    tol: &Tolerance
+) -> Result<(), BgcError> {
+    if circles.iter().any(|c| {
+        c.circle.center_point.is_equal_to(center, tol) && (c.circle.radius - radius).abs() < tol.equal_point()
+    }) {
+        return Ok(());
+    }
+
+    let mut tangent_points = Vec::with_capacity(curves.len());
+    for curve in curves {
+        match tangent_point(curve, center, radius, tol)? {
+            Some(point) => tangent_points.push(point),
+            None => return Ok(()),
+        }
+    }
+
+    let circle = Circle::new(center, &plane.get_normal_vector(tol), radius, tol)?;
+    circles.push(TangentCircle { circle, tangent_points });
+
+    Ok(())
+}
+
+/// A line or a circle in the coordinate system of the plane.
+enum Shape {
+    /// a\*x + b\*y + c = 0 with a unit normal
+    Line(f64, f64, f64),
+    /// (x, y, r)
+    Circle(f64, f64, f64),
+}
+
+impl Shape {
+    /// Returns the linear part (x, y, r coefficients and constant) of the circle equation
+    /// without x^2 + y^2 - r^2.
+    fn quadratic(&self, sign: f64) -> [f64; 4] {
+        match self {
+            Shape::Circle(x, y, r) => [-2.0 * x, -2.0 * y, -2.0 * sign * r, x * x + y * y - r * r],
+            Shape::Line(..) => [0.0; 4],
+        }
+    }
+}
+
+struct PlaneFrame {
+    origin: Point,
+    x_axis: Vector,
+    y_axis: Vector,
+}
+
+impl PlaneFrame {
+    fn new(curve: &Segment, plane: &Plane, tol: &Tolerance) -> Self {
+        let x_axis = match curve {
+            Segment::Line(line) => line.direction(tol),
+            Segment::Arc(arc) => arc.x_axis.normal(tol),
+        };
+
+        Self { origin: curve.start_point(),
+               x_axis,
+               y_axis: plane.get_normal_vector(tol).outer_product(&x_axis) }
+    }
+
+    fn local(&self, point: &Point) -> (f64, f64) {
+        let v = point - self.origin;
+        (v.inner_product(&self.x_axis), v.inner_product(&self.y_axis))
+    }
+
+    fn point(&self, x: f64, y: f64) -> Point {
+        self.origin + self.x_axis * x + self.y_axis * y
+    }
+
+    fn shape(&self, curve: &Segment, tol: &Tolerance) -> Shape {
+        match curve {
+            Segment::Line(line) => {
+                let (x0, y0) = self.local(&line.start_point);
+                let (x1, y1) = self.local(&line.end_point);
+                let length = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt().max(tol.equal_point());
+                let (a, b) = (-(y1 - y0) / length, (x1 - x0) / length);
+                Shape::Line(a, b, -(a * x0 + b * y0))
+            },
+            Segment::Arc(arc) => {
+                let (x, y) = self.local(&arc.center_point);
+                Shape::Circle(x, y, arc.radius)
+            },
+        }
+    }
+}
+
+/// Solves three linear equations a\*x + b\*y + c\*r = d by Cramer's rule.
+fn solve_linear(rows: &[[f64; 4]], tol: &Tolerance) -> Option<[f64; 3]> {
+    let det3 = |c0: usize, c1: usize, c2: usize| {
+        rows[0][c0] * (rows[1][c1] * rows[2][c2] - rows[1][c2] * rows[2][c1])
+            - rows[0][c1] * (rows[1][c0] * rows[2][c2] - rows[1][c2] * rows[2][c0])
+            + rows[0][c2] * (rows[1][c0] * rows[2][c1] - rows[1][c1] * rows[2][c0])
+    };
+
+    let det = det3(0, 1, 2);
+    if det.abs() < tol.calculation() {
+        return None;
+    }
+
+    Some([det3(3, 1, 2) / det, det3(0, 3, 2) / det, det3(0, 1, 3) / det])
+}
+
+/// Solves two linear equations and the circle equation `q` for (x, y, r).
+///
+/// The unknown whose column leaves the best conditioned 2x2 system is the free variable t.
+/// Q(t) is quadratic, so its coefficients follow from Q(0), Q(1) and Q(-1).
+fn solve_with_quadratic(rows: &[[f64; 4]], q: &[f64; 4], tol: &Tolerance) -> Vec<[f64; 3]> {
+    let det2 = |c0: usize, c1: usize| rows[0][c0] * rows[1][c1] - rows[0][c1] * rows[1][c0];
+
+    let (free, c0, c1) = [(2, 0, 1), (1, 0, 2), (0, 1, 2)]
+        .into_iter()
+        .max_by(|a, b| det2(a.1, a.2).abs().total_cmp(&det2(b.1, b.2).abs()))
+        .unwrap();
+    let det = det2(c0, c1);
+    if det.abs() < tol.calculation() {
+        return Vec::new();
+    }
+
+    let unknowns = |t: f64| {
+        let d0 = rows[0][3] - rows[0][free] * t;
+        let d1 = rows[1][3] - rows[1][free] * t;
+
+        let mut v = [0.0; 3];
+        v[free] = t;
+        v[c0] = (d0 * rows[1][c1] - d1 * rows[0][c1]) / det;
+        v[c1] = (rows[0][c0] * d1 - rows[1][c0] * d0) / det;
+        v
+    };
+    let eval = |t: f64| {
+        let [x, y, r] = unknowns(t);
+        x * x + y * y - r * r + q[0] * x + q[1] * y + q[2] * r + q[3]
+    };
+
+    let c = eval(0.0);
+    let a = (eval(1.0) + eval(-1.0)) / 2.0 - c;
+    let b = (eval(1.0) - eval(-1.0)) / 2.0;
+
+    let roots = if a.abs() <= tol.calculation() {
+        if b.abs() <= tol.calculation() {
+            return Vec::new();
+        }
+        vec![-c / b]
+    } else {
+        match math::quadratic_equation(a, b, c, tol) {
+            Ok((t1, t2)) => vec![t1, t2],
+            Err(_) => return Vec::new(),
+        }
+    };
+
+    roots.into_iter().map(unknowns).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_tangent(result: &TangentCircle, tol: &Tolerance) {
+        for p in &result.tangent_points {
+            assert!((result.circle.center_point.distance_to(p) - result.circle.radius).abs() < tol.equal_point());
+        }
+    }
+
+    #[test]
+    fn ttr_circles() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+
+        // Four circles in the quadrants of two crossing lines
+        let line1 = Segment::Line(Line::new(Point::new(-5.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0)));
+        let line2 = Segment::Line(Line::new(Point::new(0.0, -5.0, 0.0), Point::new(0.0, 5.0, 0.0)));
+        let results = circles_tangent_to_two(&line1, &line2, 1.0, &plane, &tol).unwrap();
+        assert_eq!(results.len(), 4);
+        for result in &results {
+            assert!((result.circle.center_point.x.abs() - 1.0).abs() < tol.equal_point());
+            assert!((result.circle.center_point.y.abs() - 1.0).abs() < tol.equal_point());
+            assert_tangent(result, &tol);
+        }
+
+        // A line and a circle
+        let circle = Segment::Arc(
+            Circle::new(&Point::new(0.0, 3.0, 0.0), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc()
+        );
+        let results = circles_tangent_to_two(&line1, &circle, 1.0, &plane, &tol).unwrap();
+        let expected = Point::new(0.0, 1.0, 0.0);
+        let below = results.iter().find(|r| r.circle.center_point.is_equal_to(&expected, &tol)).unwrap();
+        assert!(below.tangent_points[0].is_equal_to(&Point::origin(), &tol));
+        assert!(below.tangent_points[1].is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+        results.iter().for_each(|r| assert_tangent(r, &tol));
+
+        // A radius larger than the circle's gives two outside and two enclosing solutions
+        let circle = Segment::Arc(
+            Circle::new(&Point::new(0.0, 2.0, 0.0), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc()
+        );
+        let results = circles_tangent_to_two(&line1, &circle, 3.0, &plane, &tol).unwrap();
+        assert_eq!(results.len(), 4);
+        for x in [15.0_f64.sqrt(), -(15.0_f64.sqrt()), 3.0_f64.sqrt(), -(3.0_f64.sqrt())] {
+            assert!(results.iter().any(|r| r.circle.center_point.is_equal_to(&Point::new(x, 3.0, 0.0), &tol)));
+        }
+        let enclosing = results
+            .iter()
+            .find(|r| (r.circle.center_point.x - 3.0_f64.sqrt()).abs() < tol.equal_point())
+            .unwrap();
+        assert!(enclosing.tangent_points[1].is_equal_to(&Point::new(-3.0_f64.sqrt() / 2.0, 1.5, 0.0), &tol));
+        results.iter().for_each(|r| assert_tangent(r, &tol));
+    }
+
+    #[test]
+    fn ttt_of_lines() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+
+        // The incircle and the three excircles of a 3-4-5 triangle
+        let a = Segment::Line(Line::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0)));
+        let b = Segment::Line(Line::new(Point::new(4.0, 0.0, 0.0), Point::new(0.0, 3.0, 0.0)));
+        let c = Segment::Line(Line::new(Point::new(0.0, 3.0, 0.0), Point::new(0.0, 0.0, 0.0)));
+
+        let results = circles_tangent_to_three(&a, &b, &c, &plane, &tol).unwrap();
+        assert_eq!(results.len(), 4);
+        let incircle = results.iter().find(|r| (r.circle.radius - 1.0).abs() < tol.equal_point()).unwrap();
+        assert!(incircle.circle.center_point.is_equal_to(&Point::new(1.0, 1.0, 0.0), &tol));
+        assert!(incircle.tangent_points[0].is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!(incircle.tangent_points[2].is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+
+        let mut radii: Vec<f64> = results.iter().map(|r| r.circle.radius).collect();
+        radii.sort_by(|x, y| x.total_cmp(y));
+        for (radius, expected) in radii.iter().zip([1.0, 2.0, 3.0, 6.0]) {
+            assert!((radius - expected).abs() < tol.equal_point());
+        }
+    }
+
+    #[test]
+    fn ttt_of_circles() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+
+        // Three unit circles on the vertices of an equilateral triangle with side 4
+        let c1 = Segment::Arc(
+            Circle::new(&Point::new(0.0, 0.0, 0.0), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc()
+        );
+        let c2 = Segment::Arc(
+            Circle::new(&Point::new(4.0, 0.0, 0.0), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc()
+        );
+        let c3 = Segment::Arc(
+            Circle::new(&Point::new(2.0, 12.0_f64.sqrt(), 0.0), &Vector::z_axis(), 1.0, &tol).unwrap().to_arc()
+        );
+
+        let results = circles_tangent_to_three(&c1, &c2, &c3, &plane, &tol).unwrap();
+        assert_eq!(results.len(), 8);
+        results.iter().for_each(|r| assert_tangent(r, &tol));
+
+        // The inner and outer Soddy circles around the centroid
+        let centroid = Point::new(2.0, 12.0_f64.sqrt() / 3.0, 0.0);
+        let to_vertex = centroid.distance_to(&Point::origin());
+        for expected in [to_vertex - 1.0, to_vertex + 1.0] {
+            assert!(results.iter().any(|r| {
+                r.circle.center_point.is_equal_to(&centroid, &tol) && (r.circle.radius - expected).abs() < tol.equal_point()
+            }));
+        }
+
+        // Two circles and a line
+        let line = Segment::Line(Line::new(Point::new(-5.0, -1.0, 0.0), Point::new(10.0, -1.0, 0.0)));
+        let results = circles_tangent_to_three(&c1, &c2, &line, &plane, &tol).unwrap();
+        assert!(!results.is_empty());
+        for result in &results {
+            assert_tangent(result, &tol);
+            assert!((result.tangent_points[2].y + 1.0).abs() < tol.equal_point());
+        }
+
+        // A circle around c1 leaves half of the sign combinations without a solution
+        let around = Segment::Arc(
+            Circle::new(&Point::new(0.5, 0.0, 0.0), &Vector::z_axis(), 3.0, &tol).unwrap().to_arc()
+        );
+        let results = circles_tangent_to_three(&around, &c1, &c2, &plane, &tol).unwrap();
+        assert_eq!(results.len(), 4);
+        results.iter().for_each(|r| assert_tangent(r, &tol));
+    }
+}
@@ -0,0 +1,245 @@
+use super::*;
+use crate::{ math, BgcError, Tolerance };
+
+/// Number of pieces of each curve sampled before refining the Hausdorff distance.
+const REFINE_SAMPLES: usize = 64;
+
+/// Calculates the Hausdorff distance on equally spaced samples of both curves.
+pub(crate) fn discrete_hausdorff<C, D>(
+    curve1: &C,
+    curve2: &D,
+    samples: usize,
+    tol: &Tolerance
+) -> Result<CurveDeviation, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let forward = sample_deviations(curve1, curve2, samples, tol)?;
+    let backward = sample_deviations(curve2, curve1, samples, tol)?;
+
+    farthest(forward.into_iter().chain(backward.into_iter().map(swapped)))
+}
+
+/// Calculates the Hausdorff distance with the local maxima of the samples refined.
+pub(crate) fn hausdorff<C, D>(
+    curve1: &C,
+    curve2: &D,
+    tol: &Tolerance
+) -> Result<CurveDeviation, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let forward = directed_hausdorff(curve1, curve2, tol)?;
+    let backward = swapped(directed_hausdorff(curve2, curve1, tol)?);
+
+    Ok(if forward.distance >= backward.distance { forward } else { backward })
+}
+
+/// Calculates the discrete Fréchet distance on equally spaced samples of both curves.
+///
+/// The coupling is found by the dynamic programming of Eiter and Mannila. The result is
+/// the pair of samples that determines the distance.
+pub(crate) fn discrete_frechet<C, D>(
+    curve1: &C,
+    curve2: &D,
+    samples: usize,
+    tol: &Tolerance
+) -> Result<CurveDeviation, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let samples1 = curve1.divide(samples, tol)?;
+    let samples2 = curve2.divide(samples, tol)?;
+
+    // Each cell keeps the pair of samples with the largest distance on its best coupling
+    let mut cells: Vec<Vec<(usize, usize, f64)>> = vec![vec![(0, 0, 0.0); samples2.len()]; samples1.len()];
+    for i in 0..samples1.len() {
+        for j in 0..samples2.len() {
+            let distance = samples1[i].point.distance_to(&samples2[j].point);
+
+            let previous = [
+                (i > 0).then(|| cells[i - 1][j]),
+                (i > 0 && j > 0).then(|| cells[i - 1][j - 1]),
+                (j > 0).then(|| cells[i][j - 1]),
+            ];
+            let best = previous.into_iter().flatten().min_by(|a, b| a.2.total_cmp(&b.2));
+
+            cells[i][j] = match best {
+                Some(cell) if distance <= cell.2 => cell,
+                _ => (i, j, distance),
+            };
+        }
+    }
+
+    let (i, j, distance) = cells[samples1.len() - 1][samples2.len() - 1];
+    Ok(CurveDeviation { point1: samples1[i].point,
+                        param1: samples1[i].param,
+                        point2: samples2[j].point,
+                        param2: samples2[j].param,
+                        distance })
+}
+
+/// Calculates the largest distance from `from` to `to`.
+fn directed_hausdorff<C, D>(from: &C, to: &D, tol: &Tolerance) -> Result<CurveDeviation, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let deviations = sample_deviations(from, to, REFINE_SAMPLES, tol)?;
+    let mut best = farthest(deviations.iter().copied())?;
+
+    for i in 0..deviations.len() {
+        let is_local_max = (i == 0 || deviations[i - 1].distance <= deviations[i].distance)
+            && (i + 1 == deviations.len() || deviations[i].distance >= deviations[i + 1].distance);
+        if !is_local_max {
+            continue;
+        }
+
+        let low = deviations[i.saturating_sub(1)].param1;
+        let high = deviations[(i + 1).min(deviations.len() - 1)].param1;
+        let (param, _) = math::golden_section_search(
+            low,
+            high,
+            |u| Ok(-deviation_at(from, to, u, tol)?.distance),
+            tol
+        )?;
+
+        let refined = deviation_at(from, to, param, tol)?;
+        if refined.distance > best.distance {
+            best = refined;
+        }
+    }
+
+    Ok(best)
+}
+
+fn deviation_at<C, D>(from: &C, to: &D, param: f64, tol: &Tolerance) -> Result<CurveDeviation, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let point = from.point_at_param(param, false, tol)?;
+    let closest = to.project_point(&point, false, tol)?;
+
+    Ok(CurveDeviation { point1: point,
+                        param1: param,
+                        point2: closest.point,
+                        param2: closest.param,
+                        distance: closest.distance })
+}
+
+/// Calculates the distance from each sample of `from` to `to`.
+fn sample_deviations<C, D>(
+    from: &C,
+    to: &D,
+    samples: usize,
+    tol: &Tolerance
+) -> Result<Vec<CurveDeviation>, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    from.divide(samples, tol)?
+        .iter()
+        .map(|sample| deviation_at(from, to, sample.param, tol))
+        .collect()
+}
+
+fn farthest<I>(deviations: I) -> Result<CurveDeviation, BgcError>
+where
+    I: Iterator<Item = CurveDeviation>,
+{
+    deviations.max_by(|a, b| a.distance.total_cmp(&b.distance)).ok_or(BgcError::Empty)
+}
+
+fn swapped(deviation: CurveDeviation) -> CurveDeviation {
+    CurveDeviation { point1: deviation.point2,
+                     param1: deviation.param2,
+                     point2: deviation.point1,
+                     param2: deviation.param1,
+                     distance: deviation.distance }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn half_circle() -> Arc {
+        Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        }
+    }
+
+    #[test]
+    fn hausdorff_of_line_and_arc() {
+        let tol = Tolerance::default();
+        let arc = half_circle();
+        let chord = Line::new(Point::new(1.0, 0.0, 0.0), Point::new(-1.0, 0.0, 0.0));
+
+        // The top of the arc is the farthest from the chord
+        let result = arc.hausdorff_distance(&chord, &tol).unwrap();
+        assert!((result.distance - 1.0).abs() < tol.equal_point());
+        assert!(result.point1.is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        assert!(result.point2.is_equal_to(&Point::origin(), &tol));
+        assert!((result.param1 - std::f64::consts::FRAC_PI_2).abs() < tol.equal_point());
+
+        let result = chord.hausdorff_distance(&arc, &tol).unwrap();
+        assert!((result.distance - 1.0).abs() < tol.equal_point());
+
+        // Samples miss the top with an odd count
+        let result = arc.discrete_hausdorff_distance(&chord, 3, &tol).unwrap();
+        assert!(result.distance < 1.0);
+        assert!((result.distance - (std::f64::consts::PI / 3.0).sin()).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn hausdorff_of_nurbs_and_arc() {
+        let tol = Tolerance::default();
+        let arc = half_circle();
+        let quarter = NurbsCurve::new(
+            2,
+            vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0)],
+            vec![1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        // The quarter lies on the arc, but the other half of the arc is away from it
+        let result = quarter.hausdorff_distance(&arc, &tol).unwrap();
+        assert!((result.distance - 2.0_f64.sqrt()).abs() < tol.equal_point());
+        assert!(result.point1.is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        assert!(result.point2.is_equal_to(&Point::new(-1.0, 0.0, 0.0), &tol));
+
+        let directed = directed_hausdorff(&quarter, &arc, &tol).unwrap();
+        assert!(directed.distance < tol.equal_point());
+    }
+
+    #[test]
+    fn frechet_of_curves() {
+        let tol = Tolerance::default();
+        let l1 = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+        let l2 = Line::new(Point::new(0.0, 1.0, 0.0), Point::new(10.0, 1.0, 0.0));
+
+        let result = l1.discrete_frechet_distance(&l2, 10, &tol).unwrap();
+        assert!((result.distance - 1.0).abs() < tol.calculation());
+
+        // The Hausdorff distance ignores the direction, the Fréchet distance does not
+        let reversed = l2.reverse();
+        let hausdorff = l1.discrete_hausdorff_distance(&reversed, 10, &tol).unwrap();
+        let frechet = l1.discrete_frechet_distance(&reversed, 10, &tol).unwrap();
+        assert!((hausdorff.distance - 1.0).abs() < tol.calculation());
+        assert!((frechet.distance - 101.0_f64.sqrt()).abs() < tol.calculation());
+        assert!(frechet.point1.is_equal_to(&l1.start_point, &tol));
+        assert!(frechet.point2.is_equal_to(&reversed.start_point, &tol));
+
+        assert_eq!(l1.discrete_frechet_distance(&l2, 0, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+}
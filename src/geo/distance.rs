@@ -0,0 +1,295 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// Maximum angle between the tangents of a polyline segment used to seed the search.
+const SEED_ANGLE: f64 = std::f64::consts::PI / 18.0;
+
+/// Number of the nearest segment pairs refined by Newton's method.
+const MAX_SEEDS: usize = 8;
+
+const MAX_ITERATIONS: usize = 100;
+const MAX_HALVINGS: usize = 30;
+
+/// Calculates the closest points between two curves.
+///
+/// The nearest pairs of segments of both polylines are refined by Newton's method on
+/// |C1(s) - C2(t)|^2 within the domains.
+pub(crate) fn closest_points<C, D>(
+    curve1: &C,
+    curve2: &D,
+    tol: &Tolerance
+) -> Result<ClosestPoints, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let options = TessellationOptions::new(0.0, SEED_ANGLE, 0.0);
+    let poly1 = curve1.tessellate(&options, tol)?;
+    let poly2 = curve2.tessellate(&options, tol)?;
+
+    let mut seeds = Vec::with_capacity((poly1.len() - 1) * (poly2.len() - 1));
+    for a in poly1.windows(2) {
+        for b in poly2.windows(2) {
+            let (s, t) = segment_closest_params(&a[0].1, &a[1].1, &b[0].1, &b[1].1, tol);
+            let pa = a[0].1 + (a[1].1 - a[0].1) * s;
+            let pb = b[0].1 + (b[1].1 - b[0].1) * t;
+            seeds.push((pa.distance_to(&pb), a[0].0 + (a[1].0 - a[0].0) * s, b[0].0 + (b[1].0 - b[0].0) * t));
+        }
+    }
+    seeds.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    let mut best: Option<ClosestPoints> = None;
+    for &(_, s, t) in seeds.iter().take(MAX_SEEDS) {
+        let result = refine(curve1, curve2, s, t, tol)?;
+        if best.is_none_or(|b| result.distance < b.distance) {
+            best = Some(result);
+        }
+    }
+
+    best.ok_or(BgcError::Empty)
+}
+
+/// Calculates the parameters in [0, 1] of the closest points between segments p1-q1 and p2-q2.
+///
+/// Based on "Real-Time Collision Detection" 5.1.9.
+pub(crate) fn segment_closest_params(
+    p1: &Point,
+    q1: &Point,
+    p2: &Point,
+    q2: &Point,
+    tol: &Tolerance
+) -> (f64, f64) {
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+
+    let a = d1.inner_product(&d1);
+    let e = d2.inner_product(&d2);
+    let f = d2.inner_product(&r);
+    let eps = tol.equal_point() * tol.equal_point();
+
+    if a <= eps && e <= eps {
+        return (0.0, 0.0);
+    }
+    if a <= eps {
+        return (0.0, (f / e).clamp(0.0, 1.0));
+    }
+
+    let c = d1.inner_product(&r);
+    if e <= eps {
+        return ((-c / a).clamp(0.0, 1.0), 0.0);
+    }
+
+    // Parallel segments take any s, 0.0 is used
+    let b = d1.inner_product(&d2);
+    let denom = a * e - b * b;
+    let mut s = if denom > tol.equal_vector() * a * e {
+        ((b * f - c * e) / denom).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let mut t = (b * s + f) / e;
+    if t < 0.0 {
+        t = 0.0;
+        s = (-c / a).clamp(0.0, 1.0);
+    } else if t > 1.0 {
+        t = 1.0;
+        s = ((b - c) / a).clamp(0.0, 1.0);
+    }
+
+    (s, t)
+}
+
+/// Minimizes the distance between the curves from the parameters `s` and `t`.
+///
+/// Steps that do not decrease the distance are halved. Where the Hessian is not positive
+/// definite, the points are projected onto each other instead.
+fn refine<C, D>(
+    curve1: &C,
+    curve2: &D,
+    s: f64,
+    t: f64,
+    tol: &Tolerance
+) -> Result<ClosestPoints, BgcError>
+where
+    C: Curve + ?Sized,
+    D: Curve + ?Sized,
+{
+    let (s_low, s_high) = curve1.domain();
+    let (t_low, t_high) = curve2.domain();
+
+    let mut s = s.clamp(s_low, s_high);
+    let mut t = t.clamp(t_low, t_high);
+    let mut p1 = curve1.point_at_param(s, false, tol)?;
+    let mut p2 = curve2.point_at_param(t, false, tol)?;
+    let mut distance = p1.distance_to(&p2);
+
+    for _ in 0..MAX_ITERATIONS {
+        if distance <= tol.calculation() {
+            break;
+        }
+
+        let (d1s, d2s) = curve1.derivatives_at_param(s, false, tol)?;
+        let (d1t, d2t) = curve2.derivatives_at_param(t, false, tol)?;
+        let d = p1 - p2;
+
+        let gs = d.inner_product(&d1s);
+        let gt = -d.inner_product(&d1t);
+        let hss = d1s.inner_product(&d1s) + d.inner_product(&d2s);
+        let htt = d1t.inner_product(&d1t) - d.inner_product(&d2t);
+        let hst = -d1s.inner_product(&d1t);
+        let det = hss * htt - hst * hst;
+
+        let (mut ds, mut dt) = if hss > 0.0 && det > tol.calculation() * hss * htt.abs() {
+            (-(htt * gs - hst * gt) / det, -(hss * gt - hst * gs) / det)
+        } else {
+            let next_s = curve1.param_at_point(&p2, false, tol)?;
+            let next_p1 = curve1.point_at_param(next_s, false, tol)?;
+            let next_t = curve2.param_at_point(&next_p1, false, tol)?;
+            (next_s - s, next_t - t)
+        };
+
+        let mut moved = None;
+        for _ in 0..MAX_HALVINGS {
+            let next_s = (s + ds).clamp(s_low, s_high);
+            let next_t = (t + dt).clamp(t_low, t_high);
+            let next_p1 = curve1.point_at_param(next_s, false, tol)?;
+            let next_p2 = curve2.point_at_param(next_t, false, tol)?;
+            let next_distance = next_p1.distance_to(&next_p2);
+            if next_distance <= distance {
+                moved = Some(next_p1.distance_to(&p1) + next_p2.distance_to(&p2));
+                (s, t, p1, p2, distance) = (next_s, next_t, next_p1, next_p2, next_distance);
+                break;
+            }
+            ds /= 2.0;
+            dt /= 2.0;
+        }
+
+        match moved {
+            Some(step) if step > tol.calculation() * tol.calculation() => {},
+            _ => break,
+        }
+    }
+
+    Ok(ClosestPoints { point1: p1, param1: s, point2: p2, param2: t, distance })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_points_of_lines() {
+        let tol = Tolerance::default();
+
+        // Skew lines
+        let l1 = Line::new(Point::new(-5.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0));
+        let l2 = Line::new(Point::new(1.0, -5.0, 3.0), Point::new(1.0, 5.0, 3.0));
+        let result = l1.closest_points_to(&l2, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!(result.point2.is_equal_to(&Point::new(1.0, 0.0, 3.0), &tol));
+        assert!((result.param1 - 0.6).abs() < tol.calculation());
+        assert!((result.param2 - 0.5).abs() < tol.calculation());
+        assert!((result.distance - 3.0).abs() < tol.calculation());
+
+        // The closest point is at an end
+        let l3 = Line::new(Point::new(7.0, 2.0, 0.0), Point::new(9.0, 5.0, 0.0));
+        let result = l1.closest_points_to(&l3, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&l1.end_point, &tol));
+        assert!(result.point2.is_equal_to(&l3.start_point, &tol));
+
+        // Crossing lines
+        let l4 = Line::new(Point::new(2.0, -1.0, 0.0), Point::new(2.0, 1.0, 0.0));
+        assert!(l1.distance_to_curve(&l4, &tol).unwrap() < tol.calculation());
+
+        // Parallel lines
+        let l5 = Line::new(Point::new(0.0, 2.0, 0.0), Point::new(10.0, 2.0, 0.0));
+        assert!((l1.distance_to_curve(&l5, &tol).unwrap() - 2.0).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn closest_points_with_arcs() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+
+        let line = Line::new(Point::new(-5.0, 4.0, 1.0), Point::new(5.0, 4.0, 1.0));
+        let result = arc.closest_points_to(&line, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+        assert!((result.param1 - std::f64::consts::FRAC_PI_2).abs() < tol.calculation());
+        assert!(result.point2.is_equal_to(&Point::new(0.0, 4.0, 1.0), &tol));
+        assert!((result.distance - 5.0_f64.sqrt()).abs() < tol.calculation());
+
+        let result = line.closest_points_to(&arc, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(0.0, 4.0, 1.0), &tol));
+
+        let other = Arc {
+            center_point: Point::new(3.0, -3.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI * 2.0,
+        };
+        let result = arc.closest_points_to(&other, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        let expected = other.center_point + (result.point1 - other.center_point).normal(&tol);
+        assert!(result.point2.is_equal_to(&expected, &tol));
+        assert!((result.distance - (10.0_f64.sqrt() - 1.0)).abs() < tol.calculation());
+
+        // Intersecting arcs
+        let crossing = Arc {
+            center_point: Point::new(2.0, 0.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+        let result = arc.closest_points_to(&crossing, &tol).unwrap();
+        assert!(result.distance < tol.calculation());
+        assert!(result.point1.is_equal_to(&Point::new(1.0, 3.0_f64.sqrt(), 0.0), &tol));
+    }
+
+    #[test]
+    fn closest_points_with_nurbs() {
+        let tol = Tolerance::default();
+        let curve = NurbsCurve::new(
+            2,
+            vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 0.0), Point::new(2.0, 0.0, 0.0)],
+            vec![1.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        let line = Line::new(Point::new(-3.0, 3.0, 0.0), Point::new(3.0, 3.0, 0.0));
+        let result = curve.closest_points_to(&line, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(1.0, 1.0, 0.0), &tol));
+        assert!((result.param1 - 0.5).abs() < tol.calculation());
+        assert!((result.distance - 2.0).abs() < tol.calculation());
+
+        let crossing = Line::new(Point::new(-3.0, 0.5, 0.0), Point::new(3.0, 0.5, 0.0));
+        assert!(crossing.distance_to_curve(&curve, &tol).unwrap() < tol.calculation());
+
+        // A curve compared with itself
+        assert!(curve.distance_to_curve(&curve, &tol).unwrap() < tol.calculation());
+    }
+
+    #[test]
+    fn segment_closest_params_degenerate() {
+        let tol = Tolerance::default();
+        let p = Point::new(1.0, 1.0, 0.0);
+        let (s, t) = segment_closest_params(
+            &p, &p, &Point::new(0.0, 0.0, 0.0), &Point::new(4.0, 0.0, 0.0), &tol
+        );
+        assert_eq!(s, 0.0);
+        assert!((t - 0.25).abs() < tol.calculation());
+    }
+}
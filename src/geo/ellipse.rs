@@ -0,0 +1,688 @@
+use super::*;
+use crate::{ math, BgcError, Tolerance };
+
+/// Number of samples around the ellipse to start the numerical searches from.
+const SAMPLES: usize = 64;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// An elliptical arc.
+///
+/// The point at parameter t is C + major_axis\*cos(t) + minor_axis\*sin(t), so the angles
+/// are eccentric angles rather than polar angles. The axes are perpendicular and their
+/// lengths are the semi-axes. The arc runs counterclockwise around
+/// major_axis × minor_axis from `start_angle` to `end_angle`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ellipse {
+    pub center_point: Point,
+    pub major_axis: Vector,
+    pub minor_axis: Vector,
+    pub start_angle: f64,
+    pub end_angle: f64,
+}
+
+impl Ellipse {
+    /// Makes an elliptical arc from its center, semi-axes and angles.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBePositive)` - the minor axis has no length
+    /// * `Err(BgcError::InvalidInput)` - the axes are not perpendicular, the minor axis is
+    ///   the longer one, or `end_angle` does not exceed `start_angle`
+    /// * `Err(BgcError::OutOfRange)` - the sweep exceeds 2π
+    pub fn new(
+        center: &Point,
+        major_axis: &Vector,
+        minor_axis: &Vector,
+        start_angle: f64,
+        end_angle: f64,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        let (major, minor) = (major_axis.length(), minor_axis.length());
+        if minor < tol.equal_point() {
+            return Err(BgcError::MustBePositive);
+        }
+        if major_axis.inner_product(minor_axis).abs() >= tol.equal_vector() * major * minor
+                || major + tol.equal_point() < minor {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let sweep = end_angle - start_angle;
+        if sweep < tol.calculation() {
+            return Err(BgcError::InvalidInput);
+        }
+        if std::f64::consts::PI * 2.0 + tol.calculation() < sweep {
+            return Err(BgcError::OutOfRange);
+        }
+
+        Ok(Self { center_point: *center,
+                  major_axis: *major_axis,
+                  minor_axis: *minor_axis,
+                  start_angle,
+                  end_angle })
+    }
+
+    /// Makes an elliptical arc from two conjugate semi-diameters, such as the images of the
+    /// axes of a circle under an affine map.
+    ///
+    /// The arc runs through C + axis1\*cos(t) + axis2\*sin(t) from `start_angle` to
+    /// `end_angle`. The principal axes are found by shifting the parameter by t0:
+    ///
+    /// tan(2\*t0) = 2(a1.a2)/(a1.a1 - a2.a2) <br>
+    /// major = a1\*cos(t0) + a2\*sin(t0), minor = -a1\*sin(t0) + a2\*cos(t0)
+    pub fn from_conjugate_axes(
+        center: &Point,
+        axis1: &Vector,
+        axis2: &Vector,
+        start_angle: f64,
+        end_angle: f64,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        let difference = axis1.inner_product(axis1) - axis2.inner_product(axis2);
+        let t0 = 0.5 * (2.0 * axis1.inner_product(axis2)).atan2(difference);
+
+        let major_axis = *axis1 * t0.cos() + *axis2 * t0.sin();
+        let minor_axis = *axis1 * -t0.sin() + *axis2 * t0.cos();
+
+        Self::new(center, &major_axis, &minor_axis, start_angle - t0, end_angle - t0, tol)
+    }
+
+    pub fn major_radius(&self) -> f64 {
+        self.major_axis.length()
+    }
+
+    pub fn minor_radius(&self) -> f64 {
+        self.minor_axis.length()
+    }
+
+    pub fn sweep_angle(&self) -> f64 {
+        self.end_angle - self.start_angle
+    }
+
+    /// Returns the unit normal, major_axis × minor_axis.
+    pub fn normal(&self, tol: &Tolerance) -> Vector {
+        self.major_axis.outer_product(&self.minor_axis).normal(tol)
+    }
+
+    pub fn containing_plane(&self, tol: &Tolerance) -> Plane {
+        Plane::from(&self.center_point, &self.normal(tol), tol)
+    }
+
+    pub fn start_point(&self) -> Point {
+        self.calc_point_at_param(self.start_angle)
+    }
+
+    pub fn end_point(&self) -> Point {
+        self.calc_point_at_param(self.end_angle)
+    }
+
+    /// Calculates the length of this elliptical arc.
+    ///
+    /// The length has no closed form, so it is integrated numerically.
+    pub fn length(&self, tol: &Tolerance) -> Result<f64, BgcError> {
+        self.length_between(self.start_angle, self.end_angle, tol)
+    }
+
+    /// Calculates the closest point on this elliptical arc to input point.
+    pub fn closest_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Point, BgcError> {
+        let param = self.param_at_point(point, extends, tol)?;
+
+        self.point_at_param(param, true, tol)
+    }
+
+    /// Determines if input point lies on this elliptical arc.
+    pub fn contains(&self, point: &Point, extends: bool, tol: &Tolerance) -> bool {
+        if let Ok(closest) = self.closest_point(point, extends, tol) {
+            closest.is_equal_to(point, tol)
+        } else {
+            false
+        }
+    }
+
+    /// Calculates the axis-aligned bounding box as its minimum and maximum corners.
+    ///
+    /// Each coordinate c + a\*cos(t) + b\*sin(t) is extreme at atan2(b, a) + kπ.
+    pub fn bounding_box(&self) -> (Point, Point) {
+        let coefficients = [
+            (self.major_axis.x, self.minor_axis.x),
+            (self.major_axis.y, self.minor_axis.y),
+            (self.major_axis.z, self.minor_axis.z),
+        ];
+
+        let mut params = vec![self.start_angle, self.end_angle];
+        for (a, b) in coefficients {
+            let critical = b.atan2(a);
+            let first = ((self.start_angle - critical) / std::f64::consts::PI).floor() as i64;
+            let last = ((self.end_angle - critical) / std::f64::consts::PI).ceil() as i64;
+            for k in first..=last {
+                let t = critical + std::f64::consts::PI * k as f64;
+                if self.start_angle < t && t < self.end_angle {
+                    params.push(t);
+                }
+            }
+        }
+
+        let points: Vec<Point> = params.iter().map(|t| self.calc_point_at_param(*t)).collect();
+        let min = points.iter().fold(points[0], |m, p| Point::new(m.x.min(p.x), m.y.min(p.y), m.z.min(p.z)));
+        let max = points.iter().fold(points[0], |m, p| Point::new(m.x.max(p.x), m.y.max(p.y), m.z.max(p.z)));
+
+        (min, max)
+    }
+
+    /// Converts this elliptical arc to an exact rational quadratic curve.
+    ///
+    /// The pieces of the circular arc of `Arc::to_nurbs` are mapped by the affine map from
+    /// the unit circle to this ellipse. The knots are the angles of the joints.
+    pub fn to_nurbs(&self, tol: &Tolerance) -> Result<NurbsCurve, BgcError> {
+        let sweep = self.sweep_angle();
+        let pieces = (sweep / std::f64::consts::FRAC_PI_2 - tol.calculation()).ceil().max(1.0) as usize;
+        let angle = sweep / pieces as f64;
+        let half = angle / 2.0;
+
+        let mut control_points = vec![self.start_point()];
+        let mut weights = vec![1.0];
+        let mut knots = vec![self.start_angle; 3];
+        for i in 0..pieces {
+            let a0 = self.start_angle + angle * i as f64;
+            let tangent = self.major_axis * -a0.sin() + self.minor_axis * a0.cos();
+
+            control_points.push(self.calc_point_at_param(a0) + tangent * half.tan());
+            weights.push(half.cos());
+            control_points.push(self.calc_point_at_param(a0 + angle));
+            weights.push(1.0);
+
+            let knot = if i + 1 == pieces { self.end_angle } else { a0 + angle };
+            let multiplicity = if i + 1 == pieces { 3 } else { 2 };
+            knots.extend(std::iter::repeat_n(knot, multiplicity));
+        }
+
+        NurbsCurve::new(2, control_points, weights, knots, tol)
+    }
+
+    fn calc_point_at_param(&self, param: f64) -> Point {
+        self.center_point + self.major_axis * param.cos() + self.minor_axis * param.sin()
+    }
+
+    /// Returns the parameter of the angle on the turn nearest to the domain.
+    fn param_at_angle(&self, angle: f64, tol: &Tolerance) -> f64 {
+        let full = std::f64::consts::PI * 2.0;
+        let param = self.start_angle + (angle - self.start_angle).rem_euclid(full);
+
+        if param - self.end_angle > self.start_angle - (param - full) - tol.calculation() {
+            param - full
+        } else {
+            param
+        }
+    }
+
+    fn is_param_in_range(&self, param: f64, tol: &Tolerance) -> bool {
+        self.start_angle - tol.calculation() <= param && param <= self.end_angle + tol.calculation()
+    }
+
+    /// Calculates the points on this ellipse at the angles, as (parameter, point) without
+    /// duplicates. Unless `extends` is true, points off the arc are left out.
+    fn points_at_angles(&self, angles: &[f64], extends: bool, tol: &Tolerance) -> Vec<(f64, Point)> {
+        let mut points: Vec<(f64, Point)> = Vec::new();
+        for angle in angles {
+            let param = self.param_at_angle(*angle, tol);
+            if !extends && !self.is_param_in_range(param, tol) {
+                continue;
+            }
+
+            let point = self.calc_point_at_param(param);
+            if !points.iter().any(|(_, p)| p.is_equal_to(&point, tol)) {
+                points.push((param, point));
+            }
+        }
+
+        points
+    }
+
+    /// Calculates the angles where the line through `origin` along `direction` crosses this
+    /// ellipse. The line must lie on the plane of the ellipse.
+    ///
+    /// In the coordinates X = x/a, Y = y/b the ellipse is the unit circle: <br>
+    /// |O + sD|^2 = 1
+    fn angles_on_line(&self, origin: &Point, direction: &Vector, tol: &Tolerance) -> Vec<f64> {
+        let scaled = |v: &Vector| {
+            (v.inner_product(&self.major_axis) / self.major_axis.inner_product(&self.major_axis),
+             v.inner_product(&self.minor_axis) / self.minor_axis.inner_product(&self.minor_axis))
+        };
+        let (ox, oy) = scaled(&(origin - self.center_point));
+        let (dx, dy) = scaled(&direction.normal(tol));
+
+        let a = dx * dx + dy * dy;
+        let b = 2.0 * (ox * dx + oy * dy);
+        let c = ox * ox + oy * oy - 1.0;
+
+        // The square of the half chord, slightly negative for a tangent line after rounding
+        let half_sq = (b * b - 4.0 * a * c) / (4.0 * a * a);
+        if half_sq < -tol.equal_point() * self.major_radius() {
+            return Vec::new();
+        }
+
+        let mid = -b / (2.0 * a);
+        let half = half_sq.max(0.0).sqrt();
+
+        [mid - half, mid + half].iter().map(|s| (oy + s * dy).atan2(ox + s * dx)).collect()
+    }
+
+    /// Calculates the angles where the distance to `center` equals `radius`. The center
+    /// must lie on the plane of the ellipse.
+    ///
+    /// g(t) = |E(t) - C| - r <br>
+    /// Crossings are bracketed by sampling and refined by bisection. Touching points are
+    /// found at the minima of |g|.
+    fn angles_on_circle(&self, center: &Point, radius: f64, tol: &Tolerance) -> Result<Vec<f64>, BgcError> {
+        let g = |t: f64| self.calc_point_at_param(t).distance_to(center) - radius;
+        let step = std::f64::consts::PI * 2.0 / SAMPLES as f64;
+        let values: Vec<f64> = (0..=SAMPLES).map(|i| g(step * i as f64)).collect();
+
+        let mut angles = Vec::new();
+        for i in 0..SAMPLES {
+            let previous = values[(i + SAMPLES - 1) % SAMPLES];
+            if values[i] * values[i + 1] < 0.0 {
+                let (mut low, mut high) = (step * i as f64, step * (i + 1) as f64);
+                for _ in 0..MAX_ITERATIONS {
+                    let mid = (low + high) / 2.0;
+                    if g(low) * g(mid) <= 0.0 {
+                        high = mid;
+                    } else {
+                        low = mid;
+                    }
+                    if high - low < tol.convergence() * tol.convergence() {
+                        break;
+                    }
+                }
+                angles.push((low + high) / 2.0);
+            } else if values[i].abs() <= previous.abs() && values[i].abs() <= values[i + 1].abs() {
+                let t = step * i as f64;
+                let (t, value) = math::golden_section_search(t - step, t + step, |t| Ok(g(t).abs()), tol)?;
+                if value < tol.equal_point() {
+                    angles.push(t);
+                }
+            }
+        }
+
+        Ok(angles)
+    }
+
+    /// Returns the points as the result of an intersection.
+    fn intersection_result(points: Vec<Point>) -> Result<Vec<Point>, BgcError> {
+        if points.is_empty() {
+            Err(BgcError::InvalidInput)
+        } else {
+            Ok(points)
+        }
+    }
+}
+
+impl Curve for Ellipse {
+    /// The domain is the range of the eccentric angle.
+    fn domain(&self) -> (f64, f64) {
+        (self.start_angle, self.end_angle)
+    }
+
+    fn point_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Point, BgcError> {
+        if !extends && !self.is_param_in_range(param, tol) {
+            return Err(BgcError::OutOfRange);
+        }
+
+        Ok(self.calc_point_at_param(param))
+    }
+
+    fn derivatives_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<(Vector, Vector), BgcError> {
+        if !extends && !self.is_param_in_range(param, tol) {
+            return Err(BgcError::OutOfRange);
+        }
+
+        let first = self.major_axis * -param.sin() + self.minor_axis * param.cos();
+        let second = (self.major_axis * param.cos() + self.minor_axis * param.sin()) * -1.0;
+
+        Ok((first, second))
+    }
+
+    /// Minimizes the distance in the plane by Newton's method from the nearest sample:
+    ///
+    /// D(t) = (a\*cos(t) - u)^2 + (b\*sin(t) - v)^2 <br>
+    /// D'(t)/2 = (b^2 - a^2)sin(t)cos(t) + a\*u\*sin(t) - b\*v\*cos(t)
+    fn param_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        let (a, b) = (self.major_radius(), self.minor_radius());
+        let to_point = point - self.center_point;
+        let u = to_point.inner_product(&self.major_axis) / a;
+        let v = to_point.inner_product(&self.minor_axis) / b;
+
+        let distance_sq = |t: f64| (a * t.cos() - u).powi(2) + (b * t.sin() - v).powi(2);
+        let slope = |t: f64| (b * b - a * a) * t.sin() * t.cos() + a * u * t.sin() - b * v * t.cos();
+        let slope_derivative = |t: f64| (b * b - a * a) * (2.0 * t).cos() + a * u * t.cos() + b * v * t.sin();
+
+        let (low, high) = if extends {
+            (self.start_angle, self.start_angle + std::f64::consts::PI * 2.0)
+        } else {
+            (self.start_angle, self.end_angle)
+        };
+        let step = (high - low) / SAMPLES as f64;
+        let mut t = (0..=SAMPLES)
+            .map(|i| low + step * i as f64)
+            .min_by(|t1, t2| distance_sq(*t1).total_cmp(&distance_sq(*t2)))
+            .unwrap();
+
+        for _ in 0..MAX_ITERATIONS {
+            let curvature = slope_derivative(t);
+            if curvature <= 0.0 {
+                break;
+            }
+
+            let delta = (slope(t) / curvature).clamp(-step, step);
+            t -= delta;
+            if !extends {
+                t = t.clamp(low, high);
+            }
+            if delta.abs() < tol.convergence() {
+                break;
+            }
+        }
+
+        if extends {
+            return Ok(self.param_at_angle(t, tol));
+        }
+
+        // The ends may be closer than the minimum inside
+        Ok([t, low, high]
+            .into_iter()
+            .min_by(|t1, t2| distance_sq(*t1).total_cmp(&distance_sq(*t2)))
+            .unwrap())
+    }
+
+    fn intersect_with_line(
+        &self,
+        other: &Line,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        let local_plane = self.containing_plane(tol);
+
+        if !other.is_parallel_with_plane(&local_plane, tol) {
+            let intersections = other.intersect_with_plane(&local_plane, extends, tol)?;
+            let points = intersections.into_iter().filter(|p| self.contains(p, extends, tol)).collect();
+            return Self::intersection_result(points);
+        }
+        if !local_plane.contains(&other.start_point, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let angles = self.angles_on_line(&other.start_point, &other.direction(tol), tol);
+        let points = self.points_at_angles(&angles, extends, tol)
+            .into_iter()
+            .map(|(_, p)| p)
+            .filter(|p| extends || other.contains(p, false, tol))
+            .collect();
+
+        Self::intersection_result(points)
+    }
+
+    fn intersect_with_arc(
+        &self,
+        other: &Arc,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        let local_plane = self.containing_plane(tol);
+        let other_plane = other.containing_plane(tol);
+
+        let points = if local_plane.is_parallel_to(&other_plane, tol) {
+            if !local_plane.is_coplanar_with(&other_plane, tol) {
+                return Err(BgcError::InvalidInput);
+            }
+
+            let angles = self.angles_on_circle(&other.center_point, other.radius, tol)?;
+            self.points_at_angles(&angles, extends, tol)
+                .into_iter()
+                .map(|(_, p)| p)
+                .filter(|p| extends || other.contains(p, false, tol))
+                .collect()
+        } else {
+            // The points lie on the line where the planes meet
+            let line = local_plane.intersect_with_plane(&other_plane, tol)?;
+            let angles = self.angles_on_line(&line.start_point, &line.direction(tol), tol);
+            self.points_at_angles(&angles, extends, tol)
+                .into_iter()
+                .map(|(_, p)| p)
+                .filter(|p| other.contains(p, extends, tol))
+                .collect()
+        };
+
+        Self::intersection_result(points)
+    }
+
+    fn intersect_with_plane(
+        &self,
+        other: &Plane,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        let local_plane = self.containing_plane(tol);
+        if local_plane.is_parallel_to(other, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let line = local_plane.intersect_with_plane(other, tol)?;
+        let angles = self.angles_on_line(&line.start_point, &line.direction(tol), tol);
+        let points = self.points_at_angles(&angles, extends, tol).into_iter().map(|(_, p)| p).collect();
+
+        Self::intersection_result(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ellipse(start_angle: f64, end_angle: f64) -> Ellipse {
+        Ellipse {
+            center_point: Point::origin(),
+            major_axis: Vector::new(2.0, 0.0, 0.0),
+            minor_axis: Vector::new(0.0, 1.0, 0.0),
+            start_angle,
+            end_angle,
+        }
+    }
+
+    #[test]
+    fn ellipse_new() {
+        let tol = Tolerance::default();
+        let center = Point::new(1.0, 2.0, 3.0);
+        let major = Vector::new(3.0, 0.0, 0.0);
+        let minor = Vector::new(0.0, 0.0, 1.0);
+
+        let e = Ellipse::new(&center, &major, &minor, 0.0, std::f64::consts::PI, &tol).unwrap();
+        assert!(e.end_point().is_equal_to(&Point::new(-2.0, 2.0, 3.0), &tol));
+        assert!(e.normal(&tol).is_equal_to(&(Vector::y_axis() * -1.0), &tol));
+
+        assert_eq!(Ellipse::new(&center, &minor, &major, 0.0, 1.0, &tol).unwrap_err(), BgcError::InvalidInput);
+        let skewed = Vector::new(1.0, 1.0, 0.0);
+        assert_eq!(Ellipse::new(&center, &major, &skewed, 0.0, 1.0, &tol).unwrap_err(), BgcError::InvalidInput);
+        assert_eq!(Ellipse::new(&center, &major, &minor, 1.0, 1.0, &tol).unwrap_err(), BgcError::InvalidInput);
+        assert_eq!(Ellipse::new(&center, &major, &minor, 0.0, 7.0, &tol).unwrap_err(), BgcError::OutOfRange);
+        let zero = Vector::new(0.0, 0.0, 0.0);
+        assert_eq!(Ellipse::new(&center, &major, &zero, 0.0, 1.0, &tol).unwrap_err(), BgcError::MustBePositive);
+    }
+
+    #[test]
+    fn ellipse_from_conjugate_axes() {
+        let tol = Tolerance::default();
+        let axis1 = Vector::new(2.0, 0.0, 0.0);
+        let axis2 = Vector::new(1.0, 1.0, 0.0);
+
+        let e = Ellipse::from_conjugate_axes(&Point::origin(), &axis1, &axis2, 0.0, 2.0, &tol).unwrap();
+        assert!(e.major_axis.inner_product(&e.minor_axis).abs() < tol.calculation());
+        assert!(e.major_radius() >= e.minor_radius());
+        assert!(e.normal(&tol).is_equal_to(&Vector::z_axis(), &tol));
+        assert!(e.start_point().is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+
+        for t in [0.0_f64, 0.5, 1.0, 2.0] {
+            let point = Point::origin() + axis1 * t.cos() + axis2 * t.sin();
+            assert!(e.contains(&point, false, &tol));
+        }
+        assert!(!e.contains(&(Point::origin() + axis1 * 3.0_f64.cos() + axis2 * 3.0_f64.sin()), false, &tol));
+    }
+
+    #[test]
+    fn ellipse_evaluation() {
+        let tol = Tolerance::default();
+        let full = ellipse(0.0, std::f64::consts::PI * 2.0);
+
+        let point = full.point_at_param(std::f64::consts::FRAC_PI_2, false, &tol).unwrap();
+        assert!(point.is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        let (d1, d2) = full.derivatives_at_param(0.0, false, &tol).unwrap();
+        assert!(d1.is_equal_to(&Vector::y_axis(), &tol));
+        assert!(d2.is_equal_to(&Vector::new(-2.0, 0.0, 0.0), &tol));
+
+        // Ramanujan's value of the perimeter is exact to about 1e-5 here
+        let perimeter = 9.688_448_220_547_675;
+        assert!((full.length(&tol).unwrap() - perimeter).abs() < tol.equal_point());
+        let half = ellipse(0.0, std::f64::consts::PI);
+        assert!((half.length(&tol).unwrap() - perimeter / 2.0).abs() < tol.equal_point());
+
+        // The curvature at the end of the major axis is a/b^2
+        assert!((full.curvature_at_param(0.0, false, &tol).unwrap() - 2.0).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn ellipse_closest_point() {
+        let tol = Tolerance::default();
+        let full = ellipse(0.0, std::f64::consts::PI * 2.0);
+
+        let closest = full.closest_point(&Point::new(0.0, 3.0, 0.0), false, &tol).unwrap();
+        assert!(closest.is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        let closest = full.closest_point(&Point::new(5.0, 0.0, 2.0), false, &tol).unwrap();
+        assert!(closest.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+
+        // The offset is normal to the curve at the closest point
+        let point = Point::new(1.0, -3.0, 0.0);
+        let param = full.param_at_point(&point, false, &tol).unwrap();
+        let (d1, _) = full.derivatives_at_param(param, false, &tol).unwrap();
+        let closest = full.point_at_param(param, false, &tol).unwrap();
+        assert!((point - closest).inner_product(&d1).abs() < tol.calculation());
+        assert!(closest.y < 0.0);
+
+        // On the upper half, the end is the closest
+        let half = ellipse(0.0, std::f64::consts::PI);
+        let closest = half.closest_point(&point, false, &tol).unwrap();
+        assert!(closest.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        let extended = half.closest_point(&point, true, &tol).unwrap();
+        assert!(extended.is_equal_to(&full.closest_point(&point, false, &tol).unwrap(), &tol));
+        assert!(half.contains(&Point::new(0.0, 1.0, 0.0), false, &tol));
+        assert!(!half.contains(&Point::new(0.0, -1.0, 0.0), false, &tol));
+        assert!(half.contains(&Point::new(0.0, -1.0, 0.0), true, &tol));
+    }
+
+    #[test]
+    fn ellipse_bounding_box() {
+        let tol = Tolerance::default();
+
+        let (min, max) = ellipse(0.0, std::f64::consts::PI * 2.0).bounding_box();
+        assert!(min.is_equal_to(&Point::new(-2.0, -1.0, 0.0), &tol));
+        assert!(max.is_equal_to(&Point::new(2.0, 1.0, 0.0), &tol));
+
+        let (min, max) = ellipse(0.0, std::f64::consts::FRAC_PI_2).bounding_box();
+        assert!(min.is_equal_to(&Point::origin(), &tol));
+        assert!(max.is_equal_to(&Point::new(2.0, 1.0, 0.0), &tol));
+
+        // Rotated by 45 degrees, the half extent is sqrt(a^2 cos^2 + b^2 sin^2)
+        let axis = Vector::new(1.0, 1.0, 0.0).normal(&tol);
+        let rotated = Ellipse {
+            major_axis: axis * 2.0,
+            minor_axis: Vector::z_axis().outer_product(&axis),
+            ..ellipse(0.0, std::f64::consts::PI * 2.0)
+        };
+        let (min, max) = rotated.bounding_box();
+        let extent = 2.5_f64.sqrt();
+        assert!(min.is_equal_to(&Point::new(-extent, -extent, 0.0), &tol));
+        assert!(max.is_equal_to(&Point::new(extent, extent, 0.0), &tol));
+    }
+
+    #[test]
+    fn ellipse_to_nurbs() {
+        let tol = Tolerance::default();
+        let e = ellipse(0.5, 4.0);
+
+        let nurbs = e.to_nurbs(&tol).unwrap();
+        assert!(nurbs.start_point(&tol).unwrap().is_equal_to(&e.start_point(), &tol));
+        assert!(nurbs.end_point(&tol).unwrap().is_equal_to(&e.end_point(), &tol));
+        for i in 0..=20 {
+            let point = e.point_at_param(0.5 + 3.5 * i as f64 / 20.0, false, &tol).unwrap();
+            assert!(nurbs.distance_to_point(&point, false, &tol).unwrap() < tol.equal_point());
+            let on_nurbs = nurbs.point_at_param(0.5 + 3.5 * i as f64 / 20.0, false, &tol).unwrap();
+            assert!(e.contains(&on_nurbs, false, &tol));
+        }
+    }
+
+    #[test]
+    fn ellipse_intersections() {
+        let tol = Tolerance::default();
+        let full = ellipse(0.0, std::f64::consts::PI * 2.0);
+
+        let line = Line::new(Point::new(-5.0, 0.5, 0.0), Point::new(5.0, 0.5, 0.0));
+        let points = full.intersect_with_line(&line, false, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|p| (p.x.abs() - 3.0_f64.sqrt()).abs() < tol.equal_point()));
+
+        let tangent = Line::new(Point::new(-5.0, 1.0, 0.0), Point::new(5.0, 1.0, 0.0));
+        let points = full.intersect_with_line(&tangent, false, &tol).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+
+        let piercing = Line::new(Point::new(0.0, -1.0, -1.0), Point::new(0.0, -1.0, 1.0));
+        assert_eq!(full.intersect_with_line(&piercing, false, &tol).unwrap().len(), 1);
+        let half = ellipse(0.0, std::f64::consts::PI);
+        assert_eq!(half.intersect_with_line(&piercing, false, &tol).unwrap_err(), BgcError::InvalidInput);
+
+        let plane = Plane::from(&Point::new(1.0, 0.0, 0.0), &Vector::x_axis(), &tol);
+        let points = full.intersect_with_plane(&plane, false, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|p| (p.y.abs() - 0.75_f64.sqrt()).abs() < tol.equal_point()));
+        assert_eq!(half.intersect_with_plane(&plane, false, &tol).unwrap().len(), 1);
+
+        // x^2/4 + y^2 = 1 and x^2 + y^2 = 2.25 meet at x^2 = 5/3
+        let circle = Circle::new(&Point::origin(), &Vector::z_axis(), 1.5, &tol).unwrap().to_arc();
+        let points = full.intersect_with_arc(&circle, false, &tol).unwrap();
+        assert_eq!(points.len(), 4);
+        for p in &points {
+            assert!((p.x.abs() - (5.0_f64 / 3.0).sqrt()).abs() < tol.equal_point());
+            assert!((p.y.abs() - (7.0_f64 / 12.0).sqrt()).abs() < tol.equal_point());
+        }
+
+        // The unit circle touches at the ends of the minor axis
+        let touching = Arc { radius: 1.0, ..circle };
+        let points = full.intersect_with_arc(&touching, false, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|p| (p.y.abs() - 1.0).abs() < tol.equal_point()));
+
+        // A circle standing on the x axis
+        let standing = Arc { radius: 2.0, y_axis: Vector::z_axis(), ..circle };
+        let points = full.intersect_with_arc(&standing, false, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|p| (p.x.abs() - 2.0).abs() < tol.equal_point()));
+    }
+}
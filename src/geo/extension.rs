@@ -0,0 +1,93 @@
+use super::*;
+use crate::{ math, BgcError, Tolerance };
+
+/// Number of samples used to search a boundary along a curve.
+const SEARCH_SAMPLES: usize = 64;
+
+/// An end of a curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurveEnd {
+    Start,
+    End,
+}
+
+/// How a spline is continued beyond its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineExtension {
+    /// Continues the polynomial of the last span.
+    Natural,
+    /// Continues along the end tangent.
+    Linear,
+    /// Continues along the circle of curvature at the end.
+    Circular,
+}
+
+/// Keeps the intersection points that lie on the curve within its domain.
+///
+/// A missing intersection is not an error here, except for intersections that are
+/// not implemented.
+pub(crate) fn points_on_curve<T: Curve + ?Sized>(
+    intersections: Result<Vec<Point>, BgcError>,
+    curve: &T,
+    tol: &Tolerance
+) -> Result<Vec<Point>, BgcError> {
+    let points = match intersections {
+        Ok(points) => points,
+        Err(BgcError::NotImplemented) => return Err(BgcError::NotImplemented),
+        Err(_) => Vec::new(),
+    };
+
+    let mut on_curve = Vec::new();
+    for p in points {
+        if curve.distance_to_point(&p, false, tol)? <= tol.equal_point() {
+            on_curve.push(p);
+        }
+    }
+
+    Ok(on_curve)
+}
+
+/// Finds the first parameter from `from` towards `to` where `distance` becomes zero.
+///
+/// The samples at the local minima of the distance are refined by golden section search.
+pub(crate) fn find_first_contact<C, F>(
+    curve: &C,
+    from: f64,
+    to: f64,
+    distance: F,
+    tol: &Tolerance
+) -> Result<Option<f64>, BgcError>
+where
+    C: Curve + ?Sized,
+    F: Fn(&Point) -> Result<f64, BgcError>,
+{
+    let eval = |u: f64| -> Result<f64, BgcError> {
+        distance(&curve.point_at_param(u, false, tol)?)
+    };
+
+    let params: Vec<f64> = (0..=SEARCH_SAMPLES)
+        .map(|i| from + (to - from) * i as f64 / SEARCH_SAMPLES as f64)
+        .collect();
+    let values = params.iter().map(|&u| eval(u)).collect::<Result<Vec<f64>, BgcError>>()?;
+
+    for i in 0..=SEARCH_SAMPLES {
+        if values[i] <= tol.equal_point() {
+            return Ok(Some(params[i]));
+        }
+
+        let is_local_min = (i == 0 || values[i - 1] >= values[i])
+            && (i == SEARCH_SAMPLES || values[i] <= values[i + 1]);
+        if !is_local_min {
+            continue;
+        }
+
+        let low = params[i.saturating_sub(1)];
+        let high = params[(i + 1).min(SEARCH_SAMPLES)];
+        let (u, value) = math::golden_section_search(low, high, &eval, tol)?;
+        if value <= tol.equal_point() {
+            return Ok(Some(u));
+        }
+    }
+
+    Ok(None)
+}
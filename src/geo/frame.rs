@@ -0,0 +1,112 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// Calculates the Frenet frame and the curvature of a curve at `param`.
+///
+/// T = C'/|C'|, B = (C' × C'')/|C' × C''|, N = B × T <br>
+/// k = |C' × C''|/|C'|^3
+///
+/// The normal and the binormal are left out where the curve is straight.
+pub(crate) fn frame_at_param<C: Curve + ?Sized>(
+    curve: &C,
+    param: f64,
+    extends: bool,
+    tol: &Tolerance
+) -> Result<CurveFrame, BgcError> {
+    let point = curve.point_at_param(param, extends, tol)?;
+    let (d1, d2) = curve.derivatives_at_param(param, extends, tol)?;
+
+    let speed = d1.length();
+    if speed < tol.equal_vector() {
+        return Err(BgcError::MustBeNonZero);
+    }
+    let tangent = d1 * (1.0 / speed);
+
+    // The sine of the angle between the derivatives keeps the check independent of the scale
+    let cross = d1.outer_product(&d2);
+    let straight = d2.length() < tol.equal_vector() || cross.length() / (speed * d2.length()) < tol.equal_vector();
+    if straight {
+        return Ok(CurveFrame { param, point, tangent, normal: None, binormal: None, curvature: 0.0 });
+    }
+
+    let binormal = cross.normal(tol);
+
+    Ok(CurveFrame { param,
+                    point,
+                    tangent,
+                    normal: Some(binormal.outer_product(&tangent)),
+                    binormal: Some(binormal),
+                    curvature: cross.length() / speed.powi(3) })
+}
+
+/// Calculates the Frenet frame of a curve at the projection of `point`.
+pub(crate) fn frame_at_point<C: Curve + ?Sized>(
+    curve: &C,
+    point: &Point,
+    extends: bool,
+    tol: &Tolerance
+) -> Result<CurveFrame, BgcError> {
+    let param = curve.param_at_point(point, extends, tol)?;
+
+    frame_at_param(curve, param, extends, tol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_of_arcs() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+
+        // The normal points to the center, the binormal along x_axis × y_axis
+        let frame = arc.frame_at_param(std::f64::consts::FRAC_PI_2, false, &tol).unwrap();
+        assert!(frame.point.is_equal_to(&Point::new(0.0, 2.0, 0.0), &tol));
+        assert!(frame.tangent.is_equal_to(&Vector::new(-1.0, 0.0, 0.0), &tol));
+        assert!(frame.normal.unwrap().is_equal_to(&Vector::new(0.0, -1.0, 0.0), &tol));
+        assert!(frame.binormal.unwrap().is_equal_to(&Vector::z_axis(), &tol));
+        assert!((frame.curvature - 0.5).abs() < tol.calculation());
+
+        // A clockwise arc turns the other way around the same center
+        let clockwise = Arc { end_angle: -std::f64::consts::PI, ..arc };
+        let frame = clockwise.frame_at_point(&Point::new(0.0, -3.0, 0.0), false, &tol).unwrap();
+        assert!(frame.point.is_equal_to(&Point::new(0.0, -2.0, 0.0), &tol));
+        assert!(frame.tangent.is_equal_to(&Vector::new(-1.0, 0.0, 0.0), &tol));
+        assert!(frame.normal.unwrap().is_equal_to(&Vector::y_axis(), &tol));
+        assert!(frame.binormal.unwrap().is_equal_to(&(Vector::z_axis() * -1.0), &tol));
+        assert!((clockwise.curvature_at_param(frame.param, false, &tol).unwrap() - 0.5).abs() < tol.calculation());
+
+        // A large arc is not mistaken for a straight curve
+        let large = Arc { radius: 1.0e6, ..arc };
+        assert!((large.curvature_at_param(0.5, false, &tol).unwrap() - 1.0e-6).abs() < 1.0e-12);
+        assert!(large.normal_at_param(0.5, false, &tol).is_ok());
+    }
+
+    #[test]
+    fn frame_of_lines() {
+        let tol = Tolerance::default();
+        let line = Line::new(Point::new(1.0, 0.0, 0.0), Point::new(1.0, 4.0, 0.0));
+
+        let frame = line.frame_at_point(&Point::new(3.0, 1.0, 0.0), false, &tol).unwrap();
+        assert!((frame.param - 0.25).abs() < tol.calculation());
+        assert!(frame.tangent.is_equal_to(&Vector::y_axis(), &tol));
+        assert!(frame.normal.is_none() && frame.binormal.is_none());
+        assert_eq!(frame.curvature, 0.0);
+
+        assert!(line.tangent_at_param(0.5, false, &tol).unwrap().is_equal_to(&Vector::y_axis(), &tol));
+        assert_eq!(line.normal_at_param(0.5, false, &tol).unwrap_err(), BgcError::MustBeNonZero);
+        assert_eq!(line.binormal_at_param(0.5, false, &tol).unwrap_err(), BgcError::MustBeNonZero);
+        assert_eq!(line.tangent_at_param(2.0, false, &tol).unwrap_err(), BgcError::OutOfRange);
+
+        let point = Line::new(Point::origin(), Point::origin());
+        assert_eq!(point.tangent_at_param(0.5, false, &tol).unwrap_err(), BgcError::MustBeNonZero);
+    }
+}
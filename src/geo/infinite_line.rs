@@ -0,0 +1,187 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// A line extending infinitely in both directions.
+#[derive(Debug, Clone, Copy)]
+pub struct InfiniteLine {
+    pub point: Point,
+    /// Unit vector
+    pub direction: Vector,
+}
+
+impl InfiniteLine {
+    pub fn new(point: Point, direction: &Vector, tol: &Tolerance) -> Result<Self, BgcError> {
+        if direction.length() < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+
+        Ok(Self { point, direction: direction.normal(tol) })
+    }
+
+    /// Makes the infinite line through a line segment.
+    pub fn from_line(line: &Line, tol: &Tolerance) -> Result<Self, BgcError> {
+        if line.length() < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Self::new(line.start_point, &(line.end_point - line.start_point), tol)
+    }
+
+    /// Returns the segment of this line between two signed distances from `point`.
+    pub fn to_line(&self, start: f64, end: f64) -> Line {
+        Line::new(self.point_at(start), self.point_at(end))
+    }
+
+    /// Returns the point at a signed distance from `point`.
+    pub fn point_at(&self, distance: f64) -> Point {
+        self.point + self.direction * distance
+    }
+
+    /// Calculates the signed distance from `point` to the projection of input point.
+    pub fn param_at_point(&self, point: &Point) -> f64 {
+        (point - self.point).inner_product(&self.direction)
+    }
+
+    /// Calculates the closest point on this line to input point.
+    pub fn closest_point(&self, point: &Point) -> Point {
+        self.point_at(self.param_at_point(point))
+    }
+
+    pub fn distance_to_point(&self, point: &Point) -> f64 {
+        self.closest_point(point).distance_to(point)
+    }
+
+    /// Determines if input point lies on this line.
+    pub fn contains(&self, point: &Point, tol: &Tolerance) -> bool {
+        self.distance_to_point(point) <= tol.equal_point()
+    }
+
+    pub fn is_parallel_to(&self, other: &Self, tol: &Tolerance) -> bool {
+        self.direction.is_parallel_to(&other.direction, tol)
+    }
+
+    /// Calculates the intersection point of input line and this line.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBeNonZero)` - the lines are parallel
+    /// * `Err(BgcError::InvalidInput)` - the lines are skew
+    pub fn intersect_with_infinite_line(&self, other: &Self, tol: &Tolerance) -> Result<Point, BgcError> {
+        let points = self.to_line(0.0, 1.0).intersect_with_line(&other.to_line(0.0, 1.0), true, tol)?;
+
+        points.into_iter().next().ok_or(BgcError::InvalidInput)
+    }
+
+    /// Calculates the intersection points of input curve and this line, ordered along
+    /// the direction.
+    pub fn intersect_with<T>(&self, curve: &T, tol: &Tolerance) -> Result<Vec<Point>, BgcError>
+    where
+        T: Curve + ?Sized
+    {
+        let intersections = curve.intersect_with_line(&self.to_line(0.0, 1.0), true, tol);
+        let mut points = extension::points_on_curve(intersections, curve, tol)?;
+        points.sort_by(|a, b| self.param_at_point(a).total_cmp(&self.param_at_point(b)));
+
+        Ok(points)
+    }
+
+    /// Calculates the intersection point of input plane and this line.
+    ///
+    /// A line parallel to the plane returns `BgcError::InvalidInput`.
+    pub fn intersect_with_plane(&self, plane: &Plane, tol: &Tolerance) -> Result<Point, BgcError> {
+        let normal = plane.get_normal_vector(tol);
+        let cos = normal.inner_product(&self.direction);
+        if cos.abs() < tol.equal_vector() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let offset = (self.point - plane.closest_point(&self.point)).inner_product(&normal);
+        Ok(self.point_at(-offset / cos))
+    }
+}
+
+impl From<&Ray> for InfiniteLine {
+    fn from(ray: &Ray) -> Self {
+        Self { point: ray.origin, direction: ray.direction }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infinite_line_closest_point() {
+        let tol = Tolerance::default();
+        let line = InfiniteLine::new(Point::new(1.0, 1.0, 0.0), &Vector::new(2.0, 0.0, 0.0), &tol).unwrap();
+
+        let p = Point::new(-5.0, 4.0, 0.0);
+        assert!(line.closest_point(&p).is_equal_to(&Point::new(-5.0, 1.0, 0.0), &tol));
+        assert!((line.param_at_point(&p) + 6.0).abs() < tol.calculation());
+        assert!((line.distance_to_point(&p) - 3.0).abs() < tol.calculation());
+        assert!(line.contains(&Point::new(100.0, 1.0, 0.0), &tol));
+
+        assert_eq!(
+            InfiniteLine::new(Point::origin(), &Vector::new(0.0, 0.0, 0.0), &tol).unwrap_err(),
+            BgcError::MustBeNonZero
+        );
+    }
+
+    #[test]
+    fn infinite_line_conversions() {
+        let tol = Tolerance::default();
+        let segment = Line::new(Point::new(1.0, 2.0, 3.0), Point::new(1.0, 2.0, 7.0));
+
+        let line = InfiniteLine::from_line(&segment, &tol).unwrap();
+        assert!(line.direction.is_equal_to(&Vector::z_axis(), &tol));
+
+        let back = line.to_line(-1.0, 4.0);
+        assert!(back.start_point.is_equal_to(&Point::new(1.0, 2.0, 2.0), &tol));
+        assert!(back.end_point.is_equal_to(&segment.end_point, &tol));
+
+        let point = Line::new(Point::origin(), Point::origin());
+        assert_eq!(InfiniteLine::from_line(&point, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn infinite_line_intersections() {
+        let tol = Tolerance::default();
+        let line = InfiniteLine::new(Point::new(0.0, 1.0, 0.0), &Vector::x_axis(), &tol).unwrap();
+
+        let other = InfiniteLine::new(Point::new(3.0, -2.0, 0.0), &Vector::y_axis(), &tol).unwrap();
+        let p = line.intersect_with_infinite_line(&other, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(3.0, 1.0, 0.0), &tol));
+
+        let parallel = InfiniteLine::new(Point::origin(), &Vector::x_axis(), &tol).unwrap();
+        assert_eq!(line.intersect_with_infinite_line(&parallel, &tol).unwrap_err(), BgcError::MustBeNonZero);
+
+        // The segment is hit far from the point defining the line
+        let segment = Line::new(Point::new(-10.0, -1.0, 0.0), Point::new(-10.0, 5.0, 0.0));
+        let points = line.intersect_with(&segment, &tol).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].is_equal_to(&Point::new(-10.0, 1.0, 0.0), &tol));
+
+        let short = Line::new(Point::new(-10.0, 2.0, 0.0), Point::new(-10.0, 5.0, 0.0));
+        assert!(line.intersect_with(&short, &tol).unwrap().is_empty());
+
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+        let points = line.intersect_with(&arc, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points[0].is_equal_to(&Point::new(-(3.0_f64.sqrt()), 1.0, 0.0), &tol));
+        assert!(points[1].is_equal_to(&Point::new(3.0_f64.sqrt(), 1.0, 0.0), &tol));
+
+        let plane = Plane::from(&Point::new(4.0, 0.0, 0.0), &Vector::new(1.0, 1.0, 0.0), &tol);
+        let p = line.intersect_with_plane(&plane, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(3.0, 1.0, 0.0), &tol));
+
+        let parallel = Plane::from(&Point::origin(), &Vector::y_axis(), &tol);
+        assert_eq!(line.intersect_with_plane(&parallel, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+}
@@ -124,6 +124,119 @@ impl Line {
         other.intersect_with_line(self, extends, tol)
     }
 
+    /// Calculates an intersection point of two lines with its parameters on both lines.
+    ///
+    /// line1 = (x - x1)/l1 = (y - y1)/m1 = (z - z1)/n1 ... this line <br>
+    /// line2 = (x - x2)/l2 = (y - y2)/m2 = (z - z2)/n2 ... other line
+    ///
+    /// ix1 = x1 + l1\*L1, iy1 = y1 + m1\*L1, iz1 = z1 + n1\*L1 ... intersection point on line1 <br>
+    /// ix2 = x2 + l2\*L2, iy2 = y2 + m2\*L2, iz2 = z2 + n2\*L2 ... intersection point on line2
+    ///
+    /// L1 = (S2\*Q + S1)/(1 - Q^2) <br>
+    /// L2 = (S1\*Q + S2)/(1 - Q^2) <br>
+    /// Q = l1\*l2 + m1\*m2 + n1\*n2 <br>
+    /// S1 = l1\*X + m1\*Y +n1\*Z <br>
+    /// S2 = -(l2\*X + m2\*Y + n2\*Z) <br>
+    /// X = x2 - x1, Y = y2 - y1, Z = z2 - z1
+    ///
+    /// The parameters are normalized, L1 and L2 divided by the lengths of the lines.
+    pub fn intersect_with_line_params(
+        &self,
+        other: &Self,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<LineIntersection, BgcError> {
+        for (point, param1) in [(self.start_point, 0.0), (self.end_point, 1.0)] {
+            if point.is_equal_to(&other.start_point, tol) {
+                return Ok(LineIntersection { point, param1, param2: 0.0 });
+            }
+            if point.is_equal_to(&other.end_point, tol) {
+                return Ok(LineIntersection { point, param1, param2: 1.0 });
+            }
+        }
+
+        if self.is_parallel(other, tol) {
+            return Err(BgcError::MustBeNonZero);
+        }
+
+        let dir1 = self.direction(tol);
+        let dir2 = other.direction(tol);
+
+        let q = dir1.inner_product(&dir2);
+
+        let start_to_start = other.start_point - self.start_point;
+
+        let s1 = dir1.inner_product(&start_to_start);
+        let s2 = -(dir2.inner_product(&start_to_start));
+
+        let l1 = (s2 * q + s1) / (1.0 - q * q);
+        let l2 = (s1 * q + s2) / (1.0 - q * q);
+
+        let int_p1 = self.start_point + dir1 * l1;
+        let int_p2 = other.start_point + dir2 * l2;
+
+        if !self.contains(&int_p1, extends, tol) || !other.contains(&int_p2, extends, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        if int_p1.is_equal_to(&int_p2, tol) {
+            return Ok(LineIntersection { point: int_p1,
+                                         param1: l1 / self.length(),
+                                         param2: l2 / other.length() });
+        }
+
+        Err(BgcError::InvalidInput)
+    }
+
+    /// Calculates the closest points between input line and this line.
+    ///
+    /// Unless `extends`, the points are clamped to the segments. The parameters are
+    /// normalized as in `intersect_with_line_params`. Parallel lines return one of the
+    /// pairs at the common distance.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::InvalidInput)` - either line has no length
+    pub fn closest_points_to_line(
+        &self,
+        other: &Self,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<ClosestPoints, BgcError> {
+        if self.length() < tol.equal_point() || other.length() < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let (s, t) = if extends {
+            let d1 = self.end_point - self.start_point;
+            let d2 = other.end_point - other.start_point;
+            let r = self.start_point - other.start_point;
+
+            let a = d1.inner_product(&d1);
+            let b = d1.inner_product(&d2);
+            let c = d1.inner_product(&r);
+            let e = d2.inner_product(&d2);
+            let f = d2.inner_product(&r);
+
+            let denom = a * e - b * b;
+            let s = if denom > tol.equal_vector() * a * e { (b * f - c * e) / denom } else { 0.0 };
+            (s, (b * s + f) / e)
+        } else {
+            distance::segment_closest_params(
+                &self.start_point, &self.end_point, &other.start_point, &other.end_point, tol
+            )
+        };
+
+        let point1 = self.start_point + (self.end_point - self.start_point) * s;
+        let point2 = other.start_point + (other.end_point - other.start_point) * t;
+
+        Ok(ClosestPoints { point1,
+                           param1: s,
+                           point2,
+                           param2: t,
+                           distance: point1.distance_to(&point2) })
+    }
+
     /// Calculates the point on this line a distance from the starting point.
     pub fn point_at_dist(
         &self,
@@ -145,6 +258,134 @@ impl Line {
         Ok(self.start_point + self.direction(tol) * distance)
     }
 
+    /// Returns a line running in the opposite direction.
+    pub fn reverse(&self) -> Self {
+        Self::new(self.end_point, self.start_point)
+    }
+
+    /// Trims this line to the parameter interval from `start_param` to `end_param`.
+    pub fn trim(&self, start_param: f64, end_param: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        if end_param <= start_param {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let start_point = self.point_at_param(start_param, false, tol)?;
+        let end_point = self.point_at_param(end_param, false, tol)?;
+        if start_point.is_equal_to(&end_point, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Ok(Self::new(start_point, end_point))
+    }
+
+    /// Splits this line into two lines at `param`.
+    pub fn split_at(&self, param: f64, tol: &Tolerance) -> Result<(Self, Self), BgcError> {
+        Ok((self.trim(0.0, param, tol)?, self.trim(param, 1.0, tol)?))
+    }
+
+    /// Splits this line into two lines at input point.
+    pub fn split_at_point(&self, point: &Point, tol: &Tolerance) -> Result<(Self, Self), BgcError> {
+        if !self.contains(point, false, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let param = self.param_at_point(point, false, tol)?;
+        self.split_at(param, tol)
+    }
+
+    /// Offsets this line by `distance` within the reference plane.
+    ///
+    /// A positive distance moves the line to the left of its direction, seen from the side
+    /// the plane normal points to.
+    pub fn offset(&self, distance: f64, plane: &Plane, tol: &Tolerance) -> Result<Self, BgcError> {
+        if !self.is_parallel_with_plane(plane, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let left = plane.get_normal_vector(tol).outer_product(&self.direction(tol));
+        let offset = left * distance;
+
+        Ok(Self::new(self.start_point + offset, self.end_point + offset))
+    }
+
+    /// Projects this line onto the plane along `direction`, or orthogonally if it is `None`.
+    ///
+    /// A line along the projection direction becomes a point.
+    pub fn project_to_plane(
+        &self,
+        plane: &Plane,
+        direction: Option<&Vector>,
+        tol: &Tolerance
+    ) -> Result<ProjectedCurve, BgcError> {
+        let start_point = plane.project_point(&self.start_point, direction, tol)?;
+        let end_point = plane.project_point(&self.end_point, direction, tol)?;
+
+        if start_point.is_equal_to(&end_point, tol) {
+            Ok(ProjectedCurve::Point(start_point))
+        } else {
+            Ok(ProjectedCurve::Line(Self::new(start_point, end_point)))
+        }
+    }
+
+    /// Extends this line by `length` at the given end.
+    pub fn extend(&self, end: CurveEnd, length: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        if length < 0.0 {
+            return Err(BgcError::MustBeNoNegative);
+        }
+        if self.length() < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let extension = self.direction(tol) * length;
+        match end {
+            CurveEnd::Start => Ok(Self::new(self.start_point - extension, self.end_point)),
+            CurveEnd::End => Ok(Self::new(self.start_point, self.end_point + extension)),
+        }
+    }
+
+    /// Extends this line at the given end until it meets the boundary curve.
+    ///
+    /// The nearest intersection beyond the end is used.
+    pub fn extend_to<T>(&self, end: CurveEnd, boundary: &T, tol: &Tolerance) -> Result<Self, BgcError>
+    where
+        T: Curve + ?Sized
+    {
+        let intersections = boundary.intersect_with_line(self, true, tol);
+        let points = extension::points_on_curve(intersections, boundary, tol)?;
+
+        self.extend_to_points(end, &points, tol)
+    }
+
+    /// Extends this line at the given end until it meets the plane.
+    pub fn extend_to_plane(&self, end: CurveEnd, plane: &Plane, tol: &Tolerance) -> Result<Self, BgcError> {
+        let points = self.intersect_with_plane(plane, true, tol).unwrap_or_default();
+
+        self.extend_to_points(end, &points, tol)
+    }
+
+    fn extend_to_points(&self, end: CurveEnd, points: &[Point], tol: &Tolerance) -> Result<Self, BgcError> {
+        if self.length() < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let margin = tol.equal_point() / self.length();
+        let mut params = Vec::with_capacity(points.len());
+        for p in points {
+            params.push(self.param_at_point(p, true, tol)?);
+        }
+
+        let param = match end {
+            CurveEnd::Start => params.into_iter().filter(|&t| t <= margin).reduce(f64::max),
+            CurveEnd::End => params.into_iter().filter(|&t| 1.0 - margin <= t).reduce(f64::min),
+        }.ok_or(BgcError::InvalidInput)?;
+
+        let point = self.point_at_param(param, true, tol)?;
+        match end {
+            CurveEnd::Start => Ok(Self::new(point, self.end_point)),
+            CurveEnd::End => Ok(Self::new(self.start_point, point)),
+        }
+    }
+
     /// Transforms this point to the coordinate system of the transformation matrix
     ///
     /// \[M\] * l = l'
@@ -157,66 +398,89 @@ impl Line {
 }
 
 impl Curve for Line {
-    /// Calculates an intersection point of two lines
-    /// 
-    /// line1 = (x - x1)/l1 = (y - y1)/m1 = (z - z1)/n1 ... this line <br>
-    /// line2 = (x - x2)/l2 = (y - y2)/m2 = (z - z2)/n2 ... other line
-    ///
-    /// ix1 = x1 + l1\*L1, iy1 = y1 + m1\*L1, iz1 = z1 + n1\*L1 ... intersection point on line1 <br>
-    /// ix2 = x2 + l2\*L2, iy2 = y2 + m2\*L2, iz2 = z2 + n2\*L2 ... intersection point on line2
-    ///
-    /// L1 = (S2\*Q + S1)/(1 - Q^2) <br>
-    /// L2 = (S1\*Q + S2)/(1 - Q^2) <br>
-    /// Q = l1\*l2 + m1\*m2 + n1\*n2 <br>
-    /// S1 = l1\*X + m1\*Y +n1\*Z <br>
-    /// S2 = -(l2\*X + m2\*Y + n2\*Z) <br>
-    /// X = x2 - x1, Y = y2 - y1, Z = z2 - z1
-    fn intersect_with_line(
+    /// The parameter is normalized, 0.0 at the start point and 1.0 at the end point.
+    fn domain(&self) -> (f64, f64) {
+        (0.0, 1.0)
+    }
+
+    fn point_at_param(
         &self,
-        other: &Self,
+        param: f64,
         extends: bool,
         tol: &Tolerance
-    ) -> Result<Vec<Point>, BgcError> {
-        if self.start_point.is_equal_to(&other.start_point, tol) 
-            || self.start_point.is_equal_to(&other.end_point, tol)
-        {
-            return Ok(vec![self.start_point]);
-        }
-        if self.end_point.is_equal_to(&other.start_point, tol) 
-            || self.end_point.is_equal_to(&other.end_point, tol)
-        {
-            return Ok(vec![self.end_point]);
+    ) -> Result<Point, BgcError> {
+        if !extends && (param < -tol.calculation() || 1.0 + tol.calculation() < param) {
+            return Err(BgcError::OutOfRange);
         }
 
-        if self.is_parallel(other, tol) {
-            return Err(BgcError::MustBeNonZero);
-        }
+        Ok(self.start_point + (self.end_point - self.start_point) * param)
+    }
 
-        let dir1 = self.direction(tol);
-        let dir2 = other.direction(tol);
+    fn derivatives_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<(Vector, Vector), BgcError> {
+        if !extends && (param < -tol.calculation() || 1.0 + tol.calculation() < param) {
+            return Err(BgcError::OutOfRange);
+        }
 
-        let q = dir1.inner_product(&dir2);
+        Ok((self.end_point - self.start_point, Vector::new(0.0, 0.0, 0.0)))
+    }
 
-        let start_to_start = other.start_point - self.start_point;
+    /// t = ((p - ps) . (pe - ps)) / |pe - ps|^2
+    fn param_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        let v = self.end_point - self.start_point;
+        let length_sq = v.inner_product(&v);
+        if length_sq.sqrt() < tol.equal_point() {
+            return Ok(0.0);
+        }
 
-        let s1 = dir1.inner_product(&start_to_start);
-        let s2 = -(dir2.inner_product(&start_to_start));
+        let t = (point - self.start_point).inner_product(&v) / length_sq;
 
-        let l1 = (s2 * q + s1) / (1.0 - q * q);
-        let l2 = (s1 * q + s2) / (1.0 - q * q);
+        if extends {
+            Ok(t)
+        } else {
+            Ok(t.clamp(0.0, 1.0))
+        }
+    }
 
-        let int_p1 = self.start_point + dir1 * l1;
-        let int_p2 = other.start_point + dir2 * l2;
+    fn length_between(
+        &self,
+        start_param: f64,
+        end_param: f64,
+        _tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        Ok((end_param - start_param) * self.length())
+    }
 
-        if !self.contains(&int_p1, extends, tol) || !other.contains(&int_p2, extends, tol) {
+    fn param_at_length(&self, length: f64, tol: &Tolerance) -> Result<f64, BgcError> {
+        if self.length() < tol.equal_point() {
             return Err(BgcError::InvalidInput);
         }
-
-        if int_p1.is_equal_to(&int_p2, tol) {
-            return Ok(vec![int_p1]);
+        if length < -tol.equal_point() || self.length() + tol.equal_point() < length {
+            return Err(BgcError::OutOfRange);
         }
 
-        Err(BgcError::InvalidInput)
+        Ok((length / self.length()).clamp(0.0, 1.0))
+    }
+
+    /// Calculates an intersection point of two lines.
+    ///
+    /// See `Line::intersect_with_line_params`.
+    fn intersect_with_line(
+        &self,
+        other: &Self,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Vec<Point>, BgcError> {
+        Ok(vec![self.intersect_with_line_params(other, extends, tol)?.point])
     }
 
     fn intersect_with_arc(
@@ -612,7 +876,7 @@ mod tests {
         let line = Line::new(Point::new(1.0, 1.0, 2.0), Point::new(3.0, 3.0, 2.0));
         let plane = Plane { param_a: 0.0, param_b: 0.0, param_c: 1.0, param_d: -5.0 };
 
-        assert_eq!(line.is_parallel_with_plane(&plane, &Tolerance::default()), true);
+        assert!(line.is_parallel_with_plane(&plane, &Tolerance::default()));
     }
 
     #[test]
@@ -620,7 +884,7 @@ mod tests {
         let line = Line::new(Point::new(1.0, 1.0, 2.0), Point::new(3.0, 3.0, 5.0));
         let plane = Plane { param_a: 0.0, param_b: 0.0, param_c: 1.0, param_d: -5.0 };
 
-        assert_eq!(line.is_parallel_with_plane(&plane, &Tolerance::default()), false);
+        assert!(!line.is_parallel_with_plane(&plane, &Tolerance::default()));
     }
 
     #[test]
@@ -628,7 +892,7 @@ mod tests {
         let line = Line::new(Point::new(0.0, 1.0, 1.0), Point::new(2.0, 3.0, 3.0));
         let plane = Plane { param_a: 1.0, param_b: -1.0, param_c: 0.0, param_d: 2.0 };
 
-        assert_eq!(line.is_parallel_with_plane(&plane, &Tolerance::default()), true);
+        assert!(line.is_parallel_with_plane(&plane, &Tolerance::default()));
     }
 
     #[test]
@@ -636,7 +900,7 @@ mod tests {
         let line = Line::new(Point::new(0.0, 2.0, 1.0), Point::new(1.0, 3.0, 1.0));
         let plane = Plane { param_a: 0.0, param_b: 0.0, param_c: 1.0, param_d: -1.0 };
 
-        assert_eq!(line.is_parallel_with_plane(&plane, &Tolerance::default()), true);
+        assert!(line.is_parallel_with_plane(&plane, &Tolerance::default()));
     }
 
     #[test]
@@ -644,7 +908,7 @@ mod tests {
         let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 1.0));
         let plane = Plane { param_a: 2.0, param_b: -2.0, param_c: 0.0, param_d: 0.0 };
 
-        assert_eq!(line.is_parallel_with_plane(&plane, &Tolerance::default()), true);
+        assert!(line.is_parallel_with_plane(&plane, &Tolerance::default()));
     }
 
     #[test]
@@ -652,7 +916,7 @@ mod tests {
         let line = Line::new(Point::new(1.0, 1.0, 1.0), Point::new(2.0, 2.0, 2.0));
         let plane = Plane { param_a: 1.0, param_b: -1.0, param_c: 0.0, param_d: 0.0 };
 
-        assert_eq!(line.is_parallel_with_plane(&plane, &Tolerance::default()), true);
+        assert!(line.is_parallel_with_plane(&plane, &Tolerance::default()));
     }
 
     #[test]
@@ -927,4 +1191,240 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert!(result[0].is_equal_to(&Point::new(10.0, 0.0, 0.0), &tol));
     }
+
+    #[test]
+    fn line_point_at_param() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(1.0, 1.0, 0.0), Point::new(5.0, 3.0, 2.0));
+
+        assert_eq!(l.domain(), (0.0, 1.0));
+
+        let p = l.point_at_param(0.5, false, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(3.0, 2.0, 1.0), &tol));
+
+        assert_eq!(l.point_at_param(1.5, false, &tol).unwrap_err(), BgcError::OutOfRange);
+        let p = l.point_at_param(-0.5, true, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(-1.0, 0.0, -1.0), &tol));
+
+        let (d1, d2) = l.derivatives_at_param(0.25, false, &tol).unwrap();
+        assert!(d1.is_equal_to(&Vector::new(4.0, 2.0, 2.0), &tol));
+        assert!(d2.is_equal_to(&Vector::new(0.0, 0.0, 0.0), &tol));
+    }
+
+    #[test]
+    fn line_param_at_point() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+
+        let t = l.param_at_point(&Point::new(2.5, 3.0, 0.0), false, &tol).unwrap();
+        assert!((t - 0.25).abs() < tol.calculation());
+
+        let t = l.param_at_point(&Point::new(-5.0, 1.0, 0.0), false, &tol).unwrap();
+        assert!((t - 0.0).abs() < tol.calculation());
+
+        let t = l.param_at_point(&Point::new(-5.0, 1.0, 0.0), true, &tol).unwrap();
+        assert!((t + 0.5).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn line_reverse() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0));
+        let r = l.reverse();
+
+        assert!(r.start_point.is_equal_to(&l.end_point, &tol));
+        assert!(r.end_point.is_equal_to(&l.start_point, &tol));
+    }
+
+    #[test]
+    fn line_trim() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+
+        let trimmed = l.trim(0.2, 0.7, &tol).unwrap();
+        assert!(trimmed.start_point.is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+        assert!(trimmed.end_point.is_equal_to(&Point::new(7.0, 0.0, 0.0), &tol));
+
+        assert_eq!(l.trim(0.7, 0.2, &tol).unwrap_err(), BgcError::InvalidInput);
+        assert_eq!(l.trim(0.5, 1.5, &tol).unwrap_err(), BgcError::OutOfRange);
+    }
+
+    #[test]
+    fn line_split() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 10.0, 0.0));
+
+        let (first, second) = l.split_at(0.3, &tol).unwrap();
+        assert!(first.start_point.is_equal_to(&l.start_point, &tol));
+        assert!(first.end_point.is_equal_to(&Point::new(3.0, 3.0, 0.0), &tol));
+        assert!(second.start_point.is_equal_to(&Point::new(3.0, 3.0, 0.0), &tol));
+        assert!(second.end_point.is_equal_to(&l.end_point, &tol));
+
+        let (first, second) = l.split_at_point(&Point::new(6.0, 6.0, 0.0), &tol).unwrap();
+        assert!((first.length() - 72.0_f64.sqrt()).abs() < tol.equal_point());
+        assert!((second.length() - 32.0_f64.sqrt()).abs() < tol.equal_point());
+
+        // Splitting at an end point leaves an empty piece
+        assert_eq!(l.split_at(0.0, &tol).unwrap_err(), BgcError::InvalidInput);
+        assert_eq!(
+            l.split_at_point(&Point::new(6.0, 5.0, 0.0), &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn line_offset() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+        let l = Line::new(Point::new(0.0, 0.0, 5.0), Point::new(10.0, 0.0, 5.0));
+
+        let left = l.offset(2.0, &plane, &tol).unwrap();
+        assert!(left.start_point.is_equal_to(&Point::new(0.0, 2.0, 5.0), &tol));
+        assert!(left.end_point.is_equal_to(&Point::new(10.0, 2.0, 5.0), &tol));
+
+        let right = l.offset(-2.0, &plane, &tol).unwrap();
+        assert!(right.start_point.is_equal_to(&Point::new(0.0, -2.0, 5.0), &tol));
+
+        // Seen from the other side of the plane, left and right are swapped
+        let flipped = Plane::from(&Point::origin(), &(Vector::z_axis() * -1.0), &tol);
+        let result = l.offset(2.0, &flipped, &tol).unwrap();
+        assert!(result.start_point.is_equal_to(&Point::new(0.0, -2.0, 5.0), &tol));
+
+        // The line is not parallel to the plane
+        let l = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 5.0));
+        assert_eq!(l.offset(2.0, &plane, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn line_extend() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 3.0, 0.0));
+
+        let extended = l.extend(CurveEnd::End, 5.0, &tol).unwrap();
+        assert!(extended.start_point.is_equal_to(&l.start_point, &tol));
+        assert!(extended.end_point.is_equal_to(&Point::new(8.0, 6.0, 0.0), &tol));
+
+        let extended = l.extend(CurveEnd::Start, 5.0, &tol).unwrap();
+        assert!(extended.start_point.is_equal_to(&Point::new(-4.0, -3.0, 0.0), &tol));
+        assert!(extended.end_point.is_equal_to(&l.end_point, &tol));
+
+        assert_eq!(l.extend(CurveEnd::End, -1.0, &tol).unwrap_err(), BgcError::MustBeNoNegative);
+    }
+
+    #[test]
+    fn line_extend_to_boundary() {
+        let tol = Tolerance::default();
+        let l = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0));
+
+        // The nearest boundary beyond the end is used
+        let near = Line::new(Point::new(5.0, -1.0, 0.0), Point::new(5.0, 1.0, 0.0));
+        let extended = l.extend_to(CurveEnd::End, &near, &tol).unwrap();
+        assert!(extended.end_point.is_equal_to(&Point::new(5.0, 0.0, 0.0), &tol));
+
+        let circle = Arc {
+            center_point: Point::new(1.0, 0.0, 0.0),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 4.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI * 2.0,
+        };
+        let extended = l.extend_to(CurveEnd::End, &circle, &tol).unwrap();
+        assert!(extended.end_point.is_equal_to(&Point::new(5.0, 0.0, 0.0), &tol));
+        let extended = l.extend_to(CurveEnd::Start, &circle, &tol).unwrap();
+        assert!(extended.start_point.is_equal_to(&Point::new(-3.0, 0.0, 0.0), &tol));
+        assert!(extended.end_point.is_equal_to(&l.end_point, &tol));
+
+        // The boundary is too short to be reached
+        let short = Line::new(Point::new(5.0, 1.0, 0.0), Point::new(5.0, 2.0, 0.0));
+        assert_eq!(l.extend_to(CurveEnd::End, &short, &tol).unwrap_err(), BgcError::InvalidInput);
+
+        let plane = Plane::from(&Point::new(-2.0, 0.0, 0.0), &Vector::new(1.0, 1.0, 0.0), &tol);
+        let extended = l.extend_to_plane(CurveEnd::Start, &plane, &tol).unwrap();
+        assert!(extended.start_point.is_equal_to(&Point::new(-2.0, 0.0, 0.0), &tol));
+        assert_eq!(l.extend_to_plane(CurveEnd::End, &plane, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn line_project_to_plane() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::origin(), &Vector::z_axis(), &tol);
+
+        let l = Line::new(Point::new(0.0, 0.0, 1.0), Point::new(3.0, 4.0, 5.0));
+        let ProjectedCurve::Line(projected) = l.project_to_plane(&plane, None, &tol).unwrap() else {
+            panic!("the projection should be a line");
+        };
+        assert!(projected.start_point.is_equal_to(&Point::new(0.0, 0.0, 0.0), &tol));
+        assert!(projected.end_point.is_equal_to(&Point::new(3.0, 4.0, 0.0), &tol));
+
+        // Along the direction of the line
+        let direction = l.end_point - l.start_point;
+        let ProjectedCurve::Point(p) = l.project_to_plane(&plane, Some(&direction), &tol).unwrap() else {
+            panic!("the projection should be a point");
+        };
+        assert!(p.is_equal_to(&Point::new(-0.75, -1.0, 0.0), &tol));
+    }
+
+    #[test]
+    fn line_intersect_with_line_params() {
+        let tol = Tolerance::default();
+        let l1 = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0));
+        let l2 = Line::new(Point::new(1.0, -1.0, 0.0), Point::new(1.0, 3.0, 0.0));
+
+        let result = l1.intersect_with_line_params(&l2, false, &tol).unwrap();
+        assert!(result.point.is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!((result.param1 - 0.25).abs() < tol.calculation());
+        assert!((result.param2 - 0.25).abs() < tol.calculation());
+
+        // Beyond the ends, the parameters leave [0, 1]
+        let l3 = Line::new(Point::new(6.0, 1.0, 0.0), Point::new(6.0, 2.0, 0.0));
+        assert_eq!(l1.intersect_with_line_params(&l3, false, &tol).unwrap_err(), BgcError::InvalidInput);
+        let result = l1.intersect_with_line_params(&l3, true, &tol).unwrap();
+        assert!((result.param1 - 1.5).abs() < tol.calculation());
+        assert!((result.param2 + 1.0).abs() < tol.calculation());
+        assert!(l1.point_at_param(result.param1, true, &tol).unwrap().is_equal_to(&result.point, &tol));
+
+        // Shared end points
+        let l4 = Line::new(Point::new(4.0, 5.0, 0.0), Point::new(4.0, 0.0, 0.0));
+        let result = l1.intersect_with_line_params(&l4, false, &tol).unwrap();
+        assert_eq!((result.param1, result.param2), (1.0, 1.0));
+    }
+
+    #[test]
+    fn line_closest_points_to_line() {
+        let tol = Tolerance::default();
+        let l1 = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0));
+
+        // Skew lines
+        let l2 = Line::new(Point::new(1.0, -2.0, 3.0), Point::new(1.0, 2.0, 3.0));
+        let result = l1.closest_points_to_line(&l2, false, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert!(result.point2.is_equal_to(&Point::new(1.0, 0.0, 3.0), &tol));
+        assert!((result.param1 - 0.25).abs() < tol.calculation());
+        assert!((result.param2 - 0.5).abs() < tol.calculation());
+        assert!((result.distance - 3.0).abs() < tol.calculation());
+
+        // The closest points are beyond the segments
+        let l3 = Line::new(Point::new(6.0, 1.0, 2.0), Point::new(6.0, 3.0, 2.0));
+        let result = l1.closest_points_to_line(&l3, true, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&Point::new(6.0, 0.0, 0.0), &tol));
+        assert!(result.point2.is_equal_to(&Point::new(6.0, 0.0, 2.0), &tol));
+        assert!((result.param1 - 1.5).abs() < tol.calculation());
+        assert!((result.param2 + 0.5).abs() < tol.calculation());
+        assert!((result.distance - 2.0).abs() < tol.calculation());
+
+        let result = l1.closest_points_to_line(&l3, false, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&l1.end_point, &tol));
+        assert!(result.point2.is_equal_to(&l3.start_point, &tol));
+        assert!((result.distance - 3.0).abs() < tol.calculation());
+
+        // Parallel lines
+        let l4 = Line::new(Point::new(-1.0, 0.0, 1.0), Point::new(2.0, 0.0, 1.0));
+        let result = l1.closest_points_to_line(&l4, true, &tol).unwrap();
+        assert!(result.point1.is_equal_to(&l1.start_point, &tol));
+        assert!((result.distance - 1.0).abs() < tol.calculation());
+
+        let point = Line::new(Point::origin(), Point::origin());
+        assert_eq!(l1.closest_points_to_line(&point, false, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
 }
@@ -68,6 +68,64 @@ impl Matrix3d {
         result
     }
 
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::new();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                result.set(i, j, self.get(j, i));
+            }
+        }
+
+        result
+    }
+
+    /// Returns the inverse matrix.
+    ///
+    /// Uses Gauss-Jordan elimination with partial pivoting.
+    pub fn inverse(&self, tol: &Tolerance) -> Result<Self, BgcError> {
+        let mut mat = self.matrix;
+        let mut inv = Self::identity().matrix;
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|&i, &j| mat[i][col].abs().total_cmp(&mat[j][col].abs()))
+                .unwrap_or(col);
+            if mat[pivot][col].abs() <= tol.calculation() {
+                return Err(BgcError::MustBeNonZero);
+            }
+            mat.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let div = mat[col][col];
+            for k in 0..4 {
+                mat[col][k] /= div;
+                inv[col][k] /= div;
+            }
+
+            for row in 0..4 {
+                if row == col {
+                    continue;
+                }
+                let factor = mat[row][col];
+                for k in 0..4 {
+                    mat[row][k] -= factor * mat[col][k];
+                    inv[row][k] -= factor * inv[col][k];
+                }
+            }
+        }
+
+        Ok(Self { matrix: inv })
+    }
+
+    /// Determines if this matrix has no projective part.
+    pub fn is_affine(&self, tol: &Tolerance) -> bool {
+        self.get(3, 0).abs() < tol.calculation()
+            && self.get(3, 1).abs() < tol.calculation()
+            && self.get(3, 2).abs() < tol.calculation()
+            && (self.get(3, 3) - 1.0).abs() < tol.calculation()
+    }
+
     /// Returns the matrix of transformation into the local coordinate system.
     pub fn transform_to_local(
         origin: &Point,
@@ -116,6 +174,56 @@ impl Default for Matrix3d {
 mod tests {
     use super::*;
 
+    #[test]
+    fn matrix3d_transpose() {
+        let mut mat = Matrix3d::new();
+        for i in 0..4 {
+            for j in 0..4 {
+                mat.set(i, j, (i * 4 + j) as f64);
+            }
+        }
+
+        let transposed = mat.transpose();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert_eq!(transposed.get(i, j), mat.get(j, i));
+            }
+        }
+    }
+
+    #[test]
+    fn matrix3d_inverse() {
+        let tol = Tolerance::default();
+
+        let mat = Matrix3d::transform_to_local(
+            &Point::new(10.0, -5.0, 3.0),
+            &Vector::new(1.0, 1.0, 0.0),
+            &Vector::new(-1.0, 1.0, 1.0),
+            &tol
+        );
+        let mut scaled = Matrix3d::identity();
+        scaled.set(0, 0, 2.0);
+        scaled.set(1, 1, 0.5);
+        scaled.set(2, 2, 4.0);
+        let mat = scaled.multiply_by(&mat);
+
+        let inv = mat.inverse(&tol).unwrap();
+        let result = mat.multiply_by(&inv);
+        let identity = Matrix3d::identity();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((result.get(i, j) - identity.get(i, j)).abs() < tol.calculation());
+            }
+        }
+
+        let p = Point::new(1.0, 2.0, 3.0);
+        let back = p.transform(&mat, &tol).unwrap().transform(&inv, &tol).unwrap();
+        assert!(back.is_equal_to(&p, &tol));
+
+        // Singular matrix
+        assert_eq!(Matrix3d::new().inverse(&tol).unwrap_err(), BgcError::MustBeNonZero);
+    }
+
     #[test]
     fn matrix3d_identity() {
         let mat = Matrix3d::identity();
@@ -220,7 +328,7 @@ mod tests {
 
         assert!((to_local.get(0, 0) - 0.577350).abs() < tol.calculation());
         assert!((to_local.get(1, 1) - 0.577350).abs() < tol.calculation());
-        assert!((to_local.get(2, 2) - 0.707107).abs() < tol.calculation());
+        assert!((to_local.get(2, 2) - std::f64::consts::FRAC_1_SQRT_2).abs() < tol.calculation());
         assert!((to_local.get(3, 3) - 1.0).abs() < tol.calculation());
 
         let transformed = origin.transform(&to_local, &tol);
@@ -0,0 +1,291 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// Nodes and weights of the 5-point Gauss-Legendre quadrature on [-1, 1].
+const GAUSS_LEGENDRE_5: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// Number of quadrature intervals per span between breakpoints.
+const INTERVALS_PER_SPAN: usize = 8;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Integrates |C'(t)| from `start_param` to `end_param`.
+pub(crate) fn length_between<C: Curve + ?Sized>(
+    curve: &C,
+    start_param: f64,
+    end_param: f64,
+    tol: &Tolerance
+) -> Result<f64, BgcError> {
+    if end_param < start_param {
+        return Ok(-length_between(curve, end_param, start_param, tol)?);
+    }
+
+    // Integrate each span separately, the speed is not smooth at the breakpoints
+    let mut bounds = vec![start_param];
+    bounds.extend(curve.breakpoints().into_iter().filter(|&b| start_param < b && b < end_param));
+    bounds.push(end_param);
+
+    let mut length = 0.0;
+    for span in bounds.windows(2) {
+        let step = (span[1] - span[0]) / INTERVALS_PER_SPAN as f64;
+        for i in 0..INTERVALS_PER_SPAN {
+            let a = span[0] + step * i as f64;
+            let half = step / 2.0;
+            for (x, w) in GAUSS_LEGENDRE_5 {
+                let (d1, _) = curve.derivatives_at_param(a + half * (x + 1.0), false, tol)?;
+                length += w * half * d1.length();
+            }
+        }
+    }
+
+    Ok(length)
+}
+
+/// Finds the parameter at `length` from the start of the domain.
+///
+/// Newton's method on s(t) - length = 0 with s'(t) = |C'(t)|, falling back to bisection
+/// when a step leaves the bracket.
+pub(crate) fn param_at_length<C: Curve + ?Sized>(
+    curve: &C,
+    length: f64,
+    tol: &Tolerance
+) -> Result<f64, BgcError> {
+    let (start, end) = curve.domain();
+    let total = curve.length_between(start, end, tol)?;
+
+    if length < -tol.equal_point() || total + tol.equal_point() < length {
+        return Err(BgcError::OutOfRange);
+    }
+    if length <= 0.0 {
+        return Ok(start);
+    }
+    if total <= length {
+        return Ok(end);
+    }
+
+    let mut low = start;
+    let mut high = end;
+    let mut t = start + (end - start) * length / total;
+
+    for _ in 0..MAX_ITERATIONS {
+        let diff = curve.length_between(start, t, tol)? - length;
+        if diff.abs() <= tol.calculation() {
+            return Ok(t);
+        }
+        if diff < 0.0 {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        let (d1, _) = curve.derivatives_at_param(t, false, tol)?;
+        let speed = d1.length();
+        let next = if speed > tol.calculation() { t - diff / speed } else { low - 1.0 };
+
+        t = if low < next && next < high { next } else { (low + high) / 2.0 };
+        if high - low <= tol.calculation() * tol.calculation() {
+            return Ok(t);
+        }
+    }
+
+    Err(BgcError::Deivergence)
+}
+
+/// Samples the point and the unit tangent of a curve at `param`.
+pub(crate) fn sample_at<C: Curve + ?Sized>(
+    curve: &C,
+    param: f64,
+    tol: &Tolerance
+) -> Result<CurveSample, BgcError> {
+    let point = curve.point_at_param(param, false, tol)?;
+    let (d1, _) = curve.derivatives_at_param(param, false, tol)?;
+
+    Ok(CurveSample { param, point, tangent: d1.normal(tol) })
+}
+
+/// Divides a curve into `count` pieces of equal length.
+pub(crate) fn divide<C: Curve + ?Sized>(
+    curve: &C,
+    count: usize,
+    tol: &Tolerance
+) -> Result<Vec<CurveSample>, BgcError> {
+    if count == 0 {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let (start, end) = curve.domain();
+    let total = curve.length_between(start, end, tol)?;
+    if total < tol.equal_point() {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let mut samples = Vec::with_capacity(count + 1);
+    for i in 0..=count {
+        let param = match i {
+            0 => start,
+            _ if i == count => end,
+            _ => curve.param_at_length(total * i as f64 / count as f64, tol)?,
+        };
+        samples.push(sample_at(curve, param, tol)?);
+    }
+
+    Ok(samples)
+}
+
+/// Places points at every `distance` along a curve from its start.
+pub(crate) fn measure<C: Curve + ?Sized>(
+    curve: &C,
+    distance: f64,
+    tol: &Tolerance
+) -> Result<Vec<CurveSample>, BgcError> {
+    if distance < tol.equal_point() {
+        return Err(BgcError::MustBePositive);
+    }
+
+    let (start, end) = curve.domain();
+    let total = curve.length_between(start, end, tol)?;
+
+    let mut samples = Vec::new();
+    let mut length = 0.0;
+    let mut i = 0;
+    while length <= total + tol.equal_point() {
+        let param = curve.param_at_length(length.min(total), tol)?;
+        samples.push(sample_at(curve, param, tol)?);
+
+        i += 1;
+        length = distance * i as f64;
+    }
+
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s_curve(tol: &Tolerance) -> NurbsCurve {
+        NurbsCurve::new(
+            3,
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 3.0, 0.0),
+                Point::new(3.0, -3.0, 0.0),
+                Point::new(4.0, 0.0, 0.0),
+                Point::new(6.0, 2.0, 1.0),
+            ],
+            vec![1.0, 1.0, 2.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0],
+            tol
+        ).unwrap()
+    }
+
+    #[test]
+    fn length_of_curves() {
+        let tol = Tolerance::default();
+
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(3.0, 4.0, 0.0));
+        assert!((line.length_between(0.0, 1.0, &tol).unwrap() - 5.0).abs() < tol.calculation());
+        assert!((line.length_between(0.2, 0.6, &tol).unwrap() - 2.0).abs() < tol.calculation());
+
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+        let length = arc.length_between(0.0, std::f64::consts::FRAC_PI_2, &tol).unwrap();
+        assert!((length - std::f64::consts::PI).abs() < tol.calculation());
+
+        // A quarter circle as a rational curve
+        let quarter = NurbsCurve::new(
+            2,
+            vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(0.0, 1.0, 0.0)],
+            vec![1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0],
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+        let length = quarter.length(&tol).unwrap();
+        assert!((length - std::f64::consts::FRAC_PI_2).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn param_at_length_of_curves() {
+        let tol = Tolerance::default();
+
+        let curve = s_curve(&tol);
+        let total = curve.length(&tol).unwrap();
+        for ratio in [0.1, 0.35, 0.5, 0.8] {
+            let t = curve.param_at_length(total * ratio, &tol).unwrap();
+            let length = curve.length_between(0.0, t, &tol).unwrap();
+            assert!((length - total * ratio).abs() < tol.equal_point());
+        }
+
+        assert_eq!(curve.param_at_length(total + 1.0, &tol).unwrap_err(), BgcError::OutOfRange);
+    }
+
+    #[test]
+    fn divide_curves() {
+        let tol = Tolerance::default();
+
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+        let samples = line.divide(4, &tol).unwrap();
+        assert_eq!(samples.len(), 5);
+        assert!(samples[1].point.is_equal_to(&Point::new(2.5, 0.0, 0.0), &tol));
+        assert!(samples[4].point.is_equal_to(&line.end_point, &tol));
+        assert!(samples.iter().all(|s| s.tangent.is_equal_to(&Vector::x_axis(), &tol)));
+
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        };
+        let samples = arc.divide(2, &tol).unwrap();
+        assert!(samples[1].point.is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        assert!(samples[1].tangent.is_equal_to(&Vector::new(-1.0, 0.0, 0.0), &tol));
+
+        let curve = s_curve(&tol);
+        let samples = curve.divide(7, &tol).unwrap();
+        assert_eq!(samples.len(), 8);
+        let piece = curve.length(&tol).unwrap() / 7.0;
+        for pair in samples.windows(2) {
+            let length = curve.length_between(pair[0].param, pair[1].param, &tol).unwrap();
+            assert!((length - piece).abs() < tol.equal_point());
+        }
+
+        assert_eq!(curve.divide(0, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn measure_curves() {
+        let tol = Tolerance::default();
+
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let samples = line.measure(3.0, &tol).unwrap();
+        assert_eq!(samples.len(), 4);
+        assert!(samples[3].point.is_equal_to(&Point::new(0.0, 9.0, 0.0), &tol));
+
+        let samples = line.measure(2.5, &tol).unwrap();
+        assert_eq!(samples.len(), 5);
+        assert!(samples[4].point.is_equal_to(&line.end_point, &tol));
+
+        let curve = s_curve(&tol);
+        let samples = curve.measure(0.25, &tol).unwrap();
+        for pair in samples.windows(2) {
+            let length = curve.length_between(pair[0].param, pair[1].param, &tol).unwrap();
+            assert!((length - 0.25).abs() < tol.equal_point());
+        }
+
+        assert_eq!(line.measure(0.0, &tol).unwrap_err(), BgcError::MustBePositive);
+    }
+}
@@ -110,6 +110,13 @@ impl NurbsCurve {
     /// Uses De Boor's algorithm extended for rational B-splines.
     pub fn evaluate(&self, u: f64, tol: &Tolerance) -> Result<Point, BgcError> {
         let k = self.find_span(u, tol)?;
+        self.de_boor(k, u, tol)
+    }
+
+    /// Evaluates the polynomial piece of knot span `k` at parameter `u`.
+    ///
+    /// `u` may lie outside the span, which gives the natural extension of the piece.
+    fn de_boor(&self, k: usize, u: f64, tol: &Tolerance) -> Result<Point, BgcError> {
         let p = self.degree;
 
         // Initialize 4D points for the active control points: k-p ..= k
@@ -132,8 +139,9 @@ impl NurbsCurve {
                 };
 
                 // Interpolate in 4D
-                for coord in 0..4 {
-                    d[j][coord] = (1.0 - alpha) * d[j - 1][coord] + alpha * d[j][coord];
+                let prev = d[j - 1];
+                for (coord, prev_coord) in d[j].iter_mut().zip(prev.iter()) {
+                    *coord = (1.0 - alpha) * prev_coord + alpha * *coord;
                 }
             }
         }
@@ -147,6 +155,109 @@ impl NurbsCurve {
         Ok(Point::new(d[p][0] / w, d[p][1] / w, d[p][2] / w))
     }
 
+    /// Finds the knot span used to evaluate `u`.
+    ///
+    /// If `extends` is true, parameters before or after the domain use the first or last span.
+    fn find_span_extended(&self, u: f64, extends: bool, tol: &Tolerance) -> Result<usize, BgcError> {
+        let (low, high) = self.domain();
+
+        if extends && u < low {
+            self.find_span(low, tol)
+        } else if extends && u > high {
+            self.find_span(high, tol)
+        } else {
+            self.find_span(u, tol)
+        }
+    }
+
+    /// Computes the nonzero basis functions and their derivatives up to `order` at `u`.
+    ///
+    /// `ders[k][j]` is the k-th derivative of the basis function N(span - p + j, p).
+    /// Based on the algorithm A2.3 of "The NURBS Book".
+    fn basis_function_derivatives(&self, span: usize, u: f64, order: usize) -> Vec<Vec<f64>> {
+        let p = self.degree;
+        let knots = &self.knots;
+
+        let mut ndu = vec![vec![0.0; p + 1]; p + 1];
+        let mut left = vec![0.0; p + 1];
+        let mut right = vec![0.0; p + 1];
+
+        ndu[0][0] = 1.0;
+        for j in 1..=p {
+            left[j] = u - knots[span + 1 - j];
+            right[j] = knots[span + j] - u;
+            let mut saved = 0.0;
+            for r in 0..j {
+                // Lower triangle holds the knot differences
+                ndu[j][r] = right[r + 1] + left[j - r];
+                let temp = ndu[r][j - 1] / ndu[j][r];
+                // Upper triangle holds the basis functions
+                ndu[r][j] = saved + right[r + 1] * temp;
+                saved = left[j - r] * temp;
+            }
+            ndu[j][j] = saved;
+        }
+
+        let mut ders = vec![vec![0.0; p + 1]; order + 1];
+        for j in 0..=p {
+            ders[0][j] = ndu[j][p];
+        }
+
+        let max_order = order.min(p);
+        let pi = p as isize;
+        let mut a = vec![vec![0.0; p + 1]; 2];
+        for r in 0..=pi {
+            let mut s1 = 0;
+            let mut s2 = 1;
+            a[0][0] = 1.0;
+
+            for k in 1..=max_order as isize {
+                let mut d = 0.0;
+                let rk = r - k;
+                let pk = pi - k;
+
+                if r >= k {
+                    a[s2][0] = a[s1][0] / ndu[(pk + 1) as usize][rk as usize];
+                    d = a[s2][0] * ndu[rk as usize][pk as usize];
+                }
+
+                let j1 = if rk >= -1 { 1 } else { -rk };
+                let j2 = if r - 1 <= pk { k - 1 } else { pi - r };
+                for j in j1..=j2 {
+                    let j = j as usize;
+                    let col = (rk + j as isize) as usize;
+                    a[s2][j] = (a[s1][j] - a[s1][j - 1]) / ndu[(pk + 1) as usize][col];
+                    d += a[s2][j] * ndu[col][pk as usize];
+                }
+
+                if r <= pk {
+                    let k = k as usize;
+                    a[s2][k] = -a[s1][k - 1] / ndu[(pk + 1) as usize][r as usize];
+                    d += a[s2][k] * ndu[r as usize][pk as usize];
+                }
+
+                ders[k as usize][r as usize] = d;
+                std::mem::swap(&mut s1, &mut s2);
+            }
+        }
+
+        let mut factor = p as f64;
+        for (k, row) in ders.iter_mut().enumerate().take(max_order + 1).skip(1) {
+            for d in row.iter_mut() {
+                *d *= factor;
+            }
+            factor *= (p - k) as f64;
+        }
+
+        ders
+    }
+
+    /// Calculates the length of the NURBS curve.
+    pub fn length(&self, tol: &Tolerance) -> Result<f64, BgcError> {
+        let (low, high) = self.domain();
+        self.length_between(low, high, tol)
+    }
+
     /// Returns the start point of the NURBS curve.
     pub fn start_point(&self, tol: &Tolerance) -> Result<Point, BgcError> {
         self.evaluate(self.knots[self.degree], tol)
@@ -157,9 +268,565 @@ impl NurbsCurve {
         let n = self.control_points.len() - 1;
         self.evaluate(self.knots[n + 1], tol)
     }
+
+    /// Projects this curve onto the plane along `direction`, or orthogonally if it is `None`.
+    ///
+    /// The projection is exact since it is applied to the control points.
+    pub fn project_to_plane(
+        &self,
+        plane: &Plane,
+        direction: Option<&Vector>,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        let control_points = self.control_points
+            .iter()
+            .map(|p| plane.project_point(p, direction, tol))
+            .collect::<Result<Vec<Point>, BgcError>>()?;
+
+        Ok(Self {
+            degree: self.degree,
+            control_points,
+            weights: self.weights.clone(),
+            knots: self.knots.clone(),
+        })
+    }
+
+    /// Returns the curve running in the opposite direction over the same domain.
+    pub fn reverse(&self) -> Self {
+        let (low, high) = self.domain();
+        self.reversed_about(low + high)
+    }
+
+    /// Inserts the knot `u` once without changing the shape of the curve.
+    ///
+    /// Based on the algorithm A5.1 of "The NURBS Book".
+    pub fn insert_knot(&self, u: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        let p = self.degree;
+        let n = self.control_points.len() - 1;
+        let (_, high) = self.domain();
+
+        if (u - high).abs() <= tol.calculation() || self.knot_multiplicity(u, tol) >= p {
+            return Err(BgcError::InvalidInput);
+        }
+        let k = self.find_span(u, tol)?;
+
+        let mut points = Vec::with_capacity(n + 2);
+        for i in 0..=n + 1 {
+            let q = if i + p <= k {
+                self.homogeneous(i)
+            } else if k < i {
+                self.homogeneous(i - 1)
+            } else {
+                let alpha = (u - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+                interpolate(self.homogeneous(i - 1), self.homogeneous(i), alpha)
+            };
+            points.push(q);
+        }
+
+        let mut knots = self.knots.clone();
+        knots.insert(k + 1, u);
+
+        Ok(Self::from_homogeneous(p, &points, knots))
+    }
+
+    /// Extends this curve by `length` at the given end.
+    ///
+    /// The parameters of the original curve are kept. The extended end must be clamped.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBeNoNegative)` - `length` is negative
+    /// * `Err(BgcError::InvalidInput)` - the end is not clamped or its tangent is zero
+    /// * `Err(BgcError::OutOfRange)` - a circular extension would exceed a full circle
+    pub fn extend(
+        &self,
+        end: CurveEnd,
+        length: f64,
+        kind: SplineExtension,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        if length < 0.0 {
+            return Err(BgcError::MustBeNoNegative);
+        }
+
+        let (low, high) = self.domain();
+        match end {
+            CurveEnd::Start => {
+                Ok(self.reversed_about(low + high).extend_end(length, kind, tol)?.reversed_about(low + high))
+            },
+            CurveEnd::End => self.extend_end(length, kind, tol),
+        }
+    }
+
+    /// Extends this curve at the given end until it meets the boundary curve.
+    ///
+    /// The extension is searched numerically for the nearest contact with the boundary.
+    pub fn extend_to<T>(
+        &self,
+        end: CurveEnd,
+        boundary: &T,
+        kind: SplineExtension,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError>
+    where
+        T: Curve + ?Sized
+    {
+        let end_point = match end {
+            CurveEnd::Start => self.start_point(tol)?,
+            CurveEnd::End => self.end_point(tol)?,
+        };
+
+        // Reaches twice as far as the farthest point of the boundary
+        let mut reach = 0.0_f64;
+        for (_, p) in boundary.tessellate(&TessellationOptions::default(), tol)? {
+            reach = reach.max(end_point.distance_to(&p));
+        }
+
+        self.extend_until(end, kind, reach * 2.0, |p| boundary.distance_to_point(p, false, tol), tol)
+    }
+
+    /// Extends this curve at the given end until it meets the plane.
+    pub fn extend_to_plane(
+        &self,
+        end: CurveEnd,
+        plane: &Plane,
+        kind: SplineExtension,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError> {
+        let (low, high) = self.domain();
+        let (end_point, tangent) = match end {
+            CurveEnd::Start => (self.start_point(tol)?, self.derivatives_at_param(low, false, tol)?.0 * -1.0),
+            CurveEnd::End => (self.end_point(tol)?, self.derivatives_at_param(high, false, tol)?.0),
+        };
+
+        // Reaches twice as far as the plane along the end tangent
+        let cos = tangent.normal(tol).inner_product(&plane.get_normal_vector(tol)).abs();
+        if cos < tol.equal_vector() {
+            return Err(BgcError::InvalidInput);
+        }
+        let reach = plane.distance_to(&end_point) / cos * 2.0;
+
+        self.extend_until(end, kind, reach, |p| Ok(plane.distance_to(p)), tol)
+    }
+
+    /// Mirrors the parameters about `sum / 2` and reverses the direction.
+    fn reversed_about(&self, sum: f64) -> Self {
+        Self {
+            degree: self.degree,
+            control_points: self.control_points.iter().rev().copied().collect(),
+            weights: self.weights.iter().rev().copied().collect(),
+            knots: self.knots.iter().rev().map(|&k| sum - k).collect(),
+        }
+    }
+
+    fn knot_multiplicity(&self, u: f64, tol: &Tolerance) -> usize {
+        self.knots.iter().filter(|&&k| (k - u).abs() <= tol.calculation()).count()
+    }
+
+    fn is_end_clamped(&self, tol: &Tolerance) -> bool {
+        let n = self.control_points.len() - 1;
+        let (_, high) = self.domain();
+
+        self.knots[n + 1..].iter().all(|&k| (k - high).abs() <= tol.calculation())
+    }
+
+    fn homogeneous(&self, i: usize) -> [f64; 4] {
+        let pt = self.control_points[i];
+        let w = self.weights[i];
+
+        [pt.x * w, pt.y * w, pt.z * w, w]
+    }
+
+    fn from_homogeneous(degree: usize, points: &[[f64; 4]], knots: Vec<f64>) -> Self {
+        Self {
+            degree,
+            control_points: points.iter().map(|q| Point::new(q[0] / q[3], q[1] / q[3], q[2] / q[3])).collect(),
+            weights: points.iter().map(|q| q[3]).collect(),
+            knots,
+        }
+    }
+
+    fn extend_end(&self, length: f64, kind: SplineExtension, tol: &Tolerance) -> Result<Self, BgcError> {
+        if !self.is_end_clamped(tol) {
+            return Err(BgcError::InvalidInput);
+        }
+        if length < tol.equal_point() {
+            return Ok(self.clone());
+        }
+
+        match kind {
+            SplineExtension::Natural => self.extend_natural(length, tol),
+            SplineExtension::Linear => self.extend_linear(length, tol),
+            SplineExtension::Circular => self.extend_circular(length, tol),
+        }
+    }
+
+    /// Extends the polynomial of the last span so that it grows by `length`.
+    fn extend_natural(&self, length: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        const MAX_ITERATIONS: usize = 100;
+
+        let (_, high) = self.domain();
+        let (d1, _) = self.derivatives_at_param(high, false, tol)?;
+        if d1.length() < tol.calculation() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        // Newton's method on the length of the extension
+        let mut u = high + length / d1.length();
+        for _ in 0..MAX_ITERATIONS {
+            let extended = self.extrapolate_last_span(u, tol)?;
+            let diff = extended.length_between(high, u, tol)? - length;
+            if diff.abs() <= tol.calculation() {
+                return Ok(extended);
+            }
+
+            let (d, _) = extended.derivatives_at_param(u, false, tol)?;
+            if d.length() < tol.calculation() {
+                return Err(BgcError::Deivergence);
+            }
+            u = (u - diff / d.length()).max((high + u) / 2.0);
+        }
+
+        Err(BgcError::Deivergence)
+    }
+
+    /// Moves the end of the last span to `u` beyond the domain.
+    ///
+    /// The last span is made a Bézier segment by knot insertion, then extrapolated by
+    /// de Casteljau's algorithm.
+    fn extrapolate_last_span(&self, u: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        let p = self.degree;
+        let (_, high) = self.domain();
+
+        let mut curve = self.clone();
+        let start = curve.knots[curve.find_span(high, tol)?];
+        while curve.knot_multiplicity(start, tol) < p {
+            curve = curve.insert_knot(start, tol)?;
+        }
+
+        let n = curve.control_points.len() - 1;
+        let s = (u - start) / (high - start);
+
+        // The first points of each level are the control points of the extended segment
+        let mut level: Vec<[f64; 4]> = (n - p..=n).map(|i| curve.homogeneous(i)).collect();
+        let mut segment = vec![level[0]];
+        for r in 1..=p {
+            for i in 0..=p - r {
+                level[i] = interpolate(level[i], level[i + 1], s);
+            }
+            segment.push(level[0]);
+        }
+
+        for (i, q) in (n - p..=n).zip(segment) {
+            if q[3] <= tol.calculation() {
+                return Err(BgcError::MustBePositive);
+            }
+            curve.control_points[i] = Point::new(q[0] / q[3], q[1] / q[3], q[2] / q[3]);
+            curve.weights[i] = q[3];
+        }
+        for knot in curve.knots.iter_mut().skip(n + 1) {
+            *knot = u;
+        }
+
+        Ok(curve)
+    }
+
+    /// Appends a straight segment along the end tangent.
+    fn extend_linear(&self, length: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        let p = self.degree;
+        let n = self.control_points.len() - 1;
+        let (_, high) = self.domain();
+
+        let (d1, _) = self.derivatives_at_param(high, false, tol)?;
+        let speed = d1.length();
+        if speed < tol.calculation() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        // Keeps the parametric speed at the joint
+        let delta = length / speed;
+        let tangent = d1.normal(tol);
+        let end_point = self.control_points[n];
+        let w = self.weights[n];
+
+        let mut curve = self.clone();
+        curve.knots.truncate(n + 1);
+        curve.knots.extend(std::iter::repeat_n(high, p));
+        curve.knots.extend(std::iter::repeat_n(high + delta, p + 1));
+        for i in 1..=p {
+            curve.control_points.push(end_point + tangent * (length * i as f64 / p as f64));
+            curve.weights.push(w);
+        }
+
+        Ok(curve)
+    }
+
+    /// Appends arcs of the circle of curvature at the end.
+    ///
+    /// Each arc spans at most 90 degrees as a rational quadratic Bézier segment, elevated
+    /// to the degree of this curve.
+    fn extend_circular(&self, length: f64, tol: &Tolerance) -> Result<Self, BgcError> {
+        let p = self.degree;
+        let n = self.control_points.len() - 1;
+        let (_, high) = self.domain();
+
+        let (d1, d2) = self.derivatives_at_param(high, false, tol)?;
+        let speed = d1.length();
+        if speed < tol.calculation() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        let binormal = d1.outer_product(&d2);
+        let curvature = binormal.length() / speed.powi(3);
+        let sweep = length * curvature;
+        if p < 2 || sweep < tol.calculation() {
+            return self.extend_linear(length, tol);
+        }
+        if sweep > std::f64::consts::PI * 2.0 + tol.calculation() {
+            return Err(BgcError::OutOfRange);
+        }
+
+        let radius = 1.0 / curvature;
+        let tangent = d1.normal(tol);
+        let normal = binormal.outer_product(&d1).normal(tol);
+        let center = self.control_points[n] + normal * radius;
+        let on_circle = |a: f64| center + (normal * -a.cos() + tangent * a.sin()) * radius;
+        let direction = |a: f64| normal * a.sin() + tangent * a.cos();
+
+        let pieces = (sweep / std::f64::consts::FRAC_PI_2).ceil() as usize;
+        let angle = sweep / pieces as f64;
+        let delta = radius * angle / speed;
+        let w = self.weights[n];
+        let homogeneous = |pt: Point, weight: f64| [pt.x * weight, pt.y * weight, pt.z * weight, weight];
+
+        let mut curve = self.clone();
+        curve.knots.truncate(n + 1);
+        curve.knots.extend(std::iter::repeat_n(high, p));
+        for i in 0..pieces {
+            let a0 = angle * i as f64;
+            let p0 = on_circle(a0);
+            let p1 = p0 + direction(a0) * (radius * (angle / 2.0).tan());
+            let p2 = on_circle(a0 + angle);
+
+            // Scaling all weights by the joint weight keeps the segment unchanged
+            let segment = vec![
+                homogeneous(p0, w),
+                homogeneous(p1, w * (angle / 2.0).cos()),
+                homogeneous(p2, w),
+            ];
+            for q in elevate_bezier(segment, p).into_iter().skip(1) {
+                curve.control_points.push(Point::new(q[0] / q[3], q[1] / q[3], q[2] / q[3]));
+                curve.weights.push(q[3]);
+            }
+
+            let multiplicity = if i + 1 == pieces { p + 1 } else { p };
+            curve.knots.extend(std::iter::repeat_n(high + delta * (i + 1) as f64, multiplicity));
+        }
+
+        Ok(curve)
+    }
+
+    fn extend_until<F>(
+        &self,
+        end: CurveEnd,
+        kind: SplineExtension,
+        reach: f64,
+        distance: F,
+        tol: &Tolerance
+    ) -> Result<Self, BgcError>
+    where
+        F: Fn(&Point) -> Result<f64, BgcError>
+    {
+        let (low, high) = self.domain();
+        if end == CurveEnd::Start {
+            return Ok(self.reversed_about(low + high)
+                .extend_until(CurveEnd::End, kind, reach, distance, tol)?
+                .reversed_about(low + high));
+        }
+
+        let mut reach = reach;
+        if kind == SplineExtension::Circular {
+            let (d1, d2) = self.derivatives_at_param(high, false, tol)?;
+            let curvature = d1.outer_product(&d2).length() / d1.length().powi(3);
+            if curvature > tol.calculation() {
+                reach = reach.min(std::f64::consts::PI * 2.0 / curvature);
+            }
+        }
+
+        let extended = self.extend_end(reach, kind, tol)?;
+        let (_, extended_high) = extended.domain();
+        let param = extension::find_first_contact(&extended, high, extended_high, distance, tol)?
+            .ok_or(BgcError::InvalidInput)?;
+
+        let length = extended.length_between(high, param, tol)?;
+        self.extend_end(length, kind, tol)
+    }
+}
+
+/// Returns `a(1 - t) + bt` of homogeneous points.
+fn interpolate(a: [f64; 4], b: [f64; 4], t: f64) -> [f64; 4] {
+    let mut q = [0.0; 4];
+    for (c, (x, y)) in q.iter_mut().zip(a.iter().zip(b.iter())) {
+        *c = x * (1.0 - t) + y * t;
+    }
+
+    q
+}
+
+/// Raises the degree of a Bézier segment of homogeneous points to `degree`.
+fn elevate_bezier(points: Vec<[f64; 4]>, degree: usize) -> Vec<[f64; 4]> {
+    let mut points = points;
+    while points.len() < degree + 1 {
+        let q = points.len() - 1;
+        let mut elevated = vec![points[0]];
+        for i in 1..=q {
+            let a = i as f64 / (q + 1) as f64;
+            elevated.push(interpolate(points[i], points[i - 1], a));
+        }
+        elevated.push(points[q]);
+        points = elevated;
+    }
+
+    points
 }
 
 impl Curve for NurbsCurve {
+    fn domain(&self) -> (f64, f64) {
+        let n = self.control_points.len() - 1;
+        (self.knots[self.degree], self.knots[n + 1])
+    }
+
+    fn point_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<Point, BgcError> {
+        let k = self.find_span_extended(param, extends, tol)?;
+        self.de_boor(k, param, tol)
+    }
+
+    /// C' = (A' - w'C) / w <br>
+    /// C'' = (A'' - 2w'C' - w''C) / w <br>
+    /// A(u) is the curve and w(u) the weight in homogeneous coordinates.
+    fn derivatives_at_param(
+        &self,
+        param: f64,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<(Vector, Vector), BgcError> {
+        let k = self.find_span_extended(param, extends, tol)?;
+        let p = self.degree;
+
+        let ders = self.basis_function_derivatives(k, param, 2);
+
+        // Derivatives of the homogeneous curve: [x*w, y*w, z*w, w]
+        let mut aw = [[0.0; 4]; 3];
+        for (order, basis) in ders.iter().enumerate() {
+            for (j, &n) in basis.iter().enumerate() {
+                let i = k - p + j;
+                let pt = self.control_points[i];
+                let w = self.weights[i];
+                aw[order][0] += n * pt.x * w;
+                aw[order][1] += n * pt.y * w;
+                aw[order][2] += n * pt.z * w;
+                aw[order][3] += n * w;
+            }
+        }
+
+        let w0 = aw[0][3];
+        if w0.abs() <= tol.calculation() {
+            return Err(BgcError::MustBeNonZero);
+        }
+        let w1 = aw[1][3];
+        let w2 = aw[2][3];
+
+        let c0 = Vector::new(aw[0][0], aw[0][1], aw[0][2]) * (1.0 / w0);
+        let a1 = Vector::new(aw[1][0], aw[1][1], aw[1][2]);
+        let a2 = Vector::new(aw[2][0], aw[2][1], aw[2][2]);
+
+        let c1 = (a1 - c0 * w1) * (1.0 / w0);
+        let c2 = (a2 - c1 * (2.0 * w1) - c0 * w2) * (1.0 / w0);
+
+        Ok((c1, c2))
+    }
+
+    /// Returns the distinct knot values within the domain.
+    fn breakpoints(&self) -> Vec<f64> {
+        let (low, high) = self.domain();
+
+        let mut breaks: Vec<f64> = Vec::new();
+        for &k in &self.knots[self.degree..=self.control_points.len()] {
+            if k < low || k > high {
+                continue;
+            }
+            if breaks.last().is_none_or(|&last| k > last) {
+                breaks.push(k);
+            }
+        }
+
+        breaks
+    }
+
+    /// Finds the closest sample on each knot span, then refines it with Newton's method.
+    ///
+    /// f(u) = C'(u) . (C(u) - P) <br>
+    /// f'(u) = C''(u) . (C(u) - P) + |C'(u)|^2
+    fn param_at_point(
+        &self,
+        point: &Point,
+        extends: bool,
+        tol: &Tolerance
+    ) -> Result<f64, BgcError> {
+        const MAX_ITERATIONS: usize = 100;
+
+        let (low, high) = self.domain();
+        let samples = 2 * (self.degree + 1);
+
+        let breaks = self.breakpoints();
+        let mut best_param = low;
+        let mut best_dist = f64::MAX;
+        for span in breaks.windows(2) {
+            for i in 0..=samples {
+                let u = span[0] + (span[1] - span[0]) * i as f64 / samples as f64;
+                let dist = self.point_at_param(u, false, tol)?.distance_to(point);
+                if dist < best_dist {
+                    best_dist = dist;
+                    best_param = u;
+                }
+            }
+        }
+
+        let mut u = best_param;
+        for _ in 0..MAX_ITERATIONS {
+            let diff = self.point_at_param(u, extends, tol)? - point;
+            let (d1, d2) = self.derivatives_at_param(u, extends, tol)?;
+
+            let f = d1.inner_product(&diff);
+            let df = d2.inner_product(&diff) + d1.inner_product(&d1);
+            if df.abs() <= tol.calculation() {
+                break;
+            }
+
+            let mut next = u - f / df;
+            if !extends {
+                next = next.clamp(low, high);
+            }
+
+            let step = (next - u).abs() * d1.length();
+            u = next;
+            if step <= tol.convergence() {
+                break;
+            }
+        }
+
+        if self.point_at_param(u, extends, tol)?.distance_to(point) <= best_dist {
+            Ok(u)
+        } else {
+            Ok(best_param)
+        }
+    }
+
     fn intersect_with_line(
         &self,
         _line: &Line,
@@ -290,4 +957,233 @@ mod tests {
         assert!(curve.find_span(-1e-5, &tol).is_err());
         assert!(curve.find_span(1.0 + 1e-5, &tol).is_err());
     }
+
+    /// Quarter circle of radius 1 as a rational quadratic curve.
+    fn quarter_circle(tol: &Tolerance) -> NurbsCurve {
+        let pts = vec![
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+        ];
+        let weights = vec![1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0];
+        let knots = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+
+        NurbsCurve::new(2, pts, weights, knots, tol).unwrap()
+    }
+
+    #[test]
+    fn test_nurbs_point_at_param() {
+        let tol = Tolerance::default();
+        let curve = quarter_circle(&tol);
+
+        assert_eq!(curve.domain(), (0.0, 1.0));
+
+        for i in 0..=10 {
+            let p = curve.point_at_param(i as f64 / 10.0, false, &tol).unwrap();
+            assert!((Vector::from(p).length() - 1.0).abs() < tol.calculation());
+        }
+
+        assert_eq!(curve.point_at_param(1.5, false, &tol).unwrap_err(), BgcError::OutOfRange);
+        assert!(curve.point_at_param(1.1, true, &tol).is_ok());
+    }
+
+    #[test]
+    fn test_nurbs_derivatives() {
+        let tol = Tolerance::default();
+        let pts = vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 2.0, 1.0),
+            Point::new(3.0, 2.0, -1.0),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(6.0, 1.0, 2.0),
+        ];
+        let weights = vec![1.0, 2.0, 0.5, 1.5, 1.0];
+        let knots = vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0];
+        let curve = NurbsCurve::new(3, pts, weights, knots, &tol).unwrap();
+
+        let h = 1.0e-4;
+        for &u in &[0.1, 0.3, 0.55, 0.9] {
+            let (d1, d2) = curve.derivatives_at_param(u, false, &tol).unwrap();
+            let p_prev = Vector::from(curve.evaluate(u - h, &tol).unwrap());
+            let p_mid = Vector::from(curve.evaluate(u, &tol).unwrap());
+            let p_next = Vector::from(curve.evaluate(u + h, &tol).unwrap());
+
+            let fd1 = (p_next - p_prev) * (0.5 / h);
+            let fd2 = (p_next - p_mid * 2.0 + p_prev) * (1.0 / (h * h));
+            assert!((d1 - fd1).length() < 1.0e-5, "u = {}: {:?} {:?}", u, d1, fd1);
+            assert!((d2 - fd2).length() < 1.0e-2, "u = {}: {:?} {:?}", u, d2, fd2);
+        }
+
+        // Quarter circle: |C''| relates to the curvature 1.0
+        let circle = quarter_circle(&tol);
+        let (d1, d2) = circle.derivatives_at_param(0.5, false, &tol).unwrap();
+        let curvature = d1.outer_product(&d2).length() / d1.length().powi(3);
+        assert!((curvature - 1.0).abs() < tol.calculation());
+    }
+
+    #[test]
+    fn test_nurbs_param_at_point() {
+        let tol = Tolerance::default();
+        let curve = quarter_circle(&tol);
+
+        let target = Point::new(2.0, 2.0, 1.0);
+        let u = curve.param_at_point(&target, false, &tol).unwrap();
+        let p = curve.evaluate(u, &tol).unwrap();
+        let expected = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(p.is_equal_to(&Point::new(expected, expected, 0.0), &tol), "{:?}", p);
+
+        // Outside the domain the end is the closest
+        let u = curve.param_at_point(&Point::new(2.0, -1.0, 0.0), false, &tol).unwrap();
+        assert!(u.abs() < tol.calculation());
+    }
+
+    /// A rational cubic S curve with spans of unequal length, lifted at the end.
+    fn uneven_s_curve(tol: &Tolerance) -> NurbsCurve {
+        let pts = vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 2.0, 0.0),
+            Point::new(3.0, -2.0, 0.0),
+            Point::new(4.0, 1.0, 0.0),
+            Point::new(6.0, 1.0, 1.0),
+        ];
+        let weights = vec![1.0, 2.0, 0.5, 1.5, 1.0];
+        let knots = vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0];
+
+        NurbsCurve::new(3, pts, weights, knots, tol).unwrap()
+    }
+
+    #[test]
+    fn test_nurbs_reverse_and_insert_knot() {
+        let tol = Tolerance::default();
+        let curve = uneven_s_curve(&tol);
+
+        let reversed = curve.reverse();
+        assert_eq!(reversed.domain(), curve.domain());
+        let refined = curve.insert_knot(0.7, &tol).unwrap();
+        assert_eq!(refined.control_points().len(), curve.control_points().len() + 1);
+
+        for i in 0..=10 {
+            let u = i as f64 / 10.0;
+            let p = curve.evaluate(u, &tol).unwrap();
+            assert!(reversed.evaluate(1.0 - u, &tol).unwrap().is_equal_to(&p, &tol));
+            assert!(refined.evaluate(u, &tol).unwrap().is_equal_to(&p, &tol));
+        }
+
+        assert_eq!(curve.insert_knot(1.0, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+
+    #[test]
+    fn test_nurbs_extend() {
+        let tol = Tolerance::default();
+        let curve = uneven_s_curve(&tol);
+        let length = curve.length(&tol).unwrap();
+
+        for kind in [SplineExtension::Natural, SplineExtension::Linear, SplineExtension::Circular] {
+            for end in [CurveEnd::Start, CurveEnd::End] {
+                let extended = curve.extend(end, 2.0, kind, &tol).unwrap();
+                let extended_length = extended.length(&tol).unwrap();
+                assert!((extended_length - length - 2.0).abs() < tol.equal_point(), "{:?} {:?}", kind, end);
+
+                // The original curve is kept with its parameters
+                for i in 0..=10 {
+                    let u = i as f64 / 10.0;
+                    let p = extended.evaluate(u, &tol).unwrap();
+                    assert!(p.is_equal_to(&curve.evaluate(u, &tol).unwrap(), &tol));
+                }
+
+                // The tangent is continuous at the joint
+                let (low, high) = curve.domain();
+                let joint = if end == CurveEnd::Start { low } else { high };
+                let before = curve.derivatives_at_param(joint, false, &tol).unwrap().0;
+                let h = 1.0e-7;
+                let after = if end == CurveEnd::Start { joint - h } else { joint + h };
+                let after = extended.derivatives_at_param(after, false, &tol).unwrap().0;
+                assert!(before.normal(&tol).is_equal_to(&after.normal(&tol), &tol));
+            }
+        }
+
+        // The natural extension follows the polynomial of the last span
+        let extended = curve.extend(CurveEnd::End, 1.0, SplineExtension::Natural, &tol).unwrap();
+        let (_, high) = extended.domain();
+        let u = (1.0 + high) / 2.0;
+        let expected = curve.point_at_param(u, true, &tol).unwrap();
+        assert!(extended.evaluate(u, &tol).unwrap().is_equal_to(&expected, &tol));
+
+        // The linear extension is straight
+        let extended = curve.extend(CurveEnd::End, 3.0, SplineExtension::Linear, &tol).unwrap();
+        let end = curve.end_point(&tol).unwrap();
+        let chord = Line::new(end, extended.end_point(&tol).unwrap());
+        let (_, high) = extended.domain();
+        assert!(chord.contains(&extended.evaluate((1.0 + high) / 2.0, &tol).unwrap(), false, &tol));
+
+        // A circle continues as the same circle
+        let circle = quarter_circle(&tol);
+        let extended = circle.extend(CurveEnd::End, std::f64::consts::PI, SplineExtension::Circular, &tol).unwrap();
+        assert!(extended.end_point(&tol).unwrap().is_equal_to(&Point::new(0.0, -1.0, 0.0), &tol));
+        for i in 0..=20 {
+            let (low, high) = extended.domain();
+            let p = extended.evaluate(low + (high - low) * i as f64 / 20.0, &tol).unwrap();
+            assert!((Vector::from(p).length() - 1.0).abs() < tol.calculation());
+        }
+
+        assert_eq!(
+            curve.extend(CurveEnd::End, -1.0, SplineExtension::Linear, &tol).unwrap_err(),
+            BgcError::MustBeNoNegative
+        );
+        assert_eq!(
+            circle.extend(CurveEnd::End, 7.0, SplineExtension::Circular, &tol).unwrap_err(),
+            BgcError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn test_nurbs_extend_to_boundary() {
+        let tol = Tolerance::default();
+        let circle = quarter_circle(&tol);
+
+        let boundary = Line::new(Point::new(-2.0, -0.5, 0.0), Point::new(2.0, -0.5, 0.0));
+        let extended = circle.extend_to(CurveEnd::End, &boundary, SplineExtension::Circular, &tol).unwrap();
+        let expected = Point::new(-(0.75_f64.sqrt()), -0.5, 0.0);
+        assert!(extended.end_point(&tol).unwrap().is_equal_to(&expected, &tol));
+
+        let extended = circle.extend_to(CurveEnd::Start, &boundary, SplineExtension::Linear, &tol).unwrap();
+        assert!(extended.start_point(&tol).unwrap().is_equal_to(&Point::new(1.0, -0.5, 0.0), &tol));
+        assert!(extended.end_point(&tol).unwrap().is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+
+        let plane = Plane::from(&Point::new(-3.0, 0.0, 0.0), &Vector::x_axis(), &tol);
+        let extended = circle.extend_to_plane(CurveEnd::End, &plane, SplineExtension::Linear, &tol).unwrap();
+        assert!(extended.end_point(&tol).unwrap().is_equal_to(&Point::new(-3.0, 1.0, 0.0), &tol));
+
+        let curve = uneven_s_curve(&tol);
+        let plane = Plane::from(&Point::new(7.0, 0.0, 0.0), &Vector::x_axis(), &tol);
+        let extended = curve.extend_to_plane(CurveEnd::End, &plane, SplineExtension::Natural, &tol).unwrap();
+        assert!((extended.end_point(&tol).unwrap().x - 7.0).abs() < tol.equal_point());
+
+        // The circle of curvature never reaches the boundary
+        let far = Line::new(Point::new(-5.0, -5.0, 0.0), Point::new(-5.0, 5.0, 0.0));
+        assert_eq!(
+            circle.extend_to(CurveEnd::End, &far, SplineExtension::Circular, &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_nurbs_project_to_plane() {
+        let tol = Tolerance::default();
+        let curve = uneven_s_curve(&tol);
+        let plane = Plane::from(&Point::new(0.0, 0.0, -1.0), &Vector::new(0.0, 1.0, 1.0), &tol);
+
+        let projected = curve.project_to_plane(&plane, None, &tol).unwrap();
+        for i in 0..=10 {
+            let u = i as f64 / 10.0;
+            let expected = plane.closest_point(&curve.evaluate(u, &tol).unwrap());
+            assert!(projected.evaluate(u, &tol).unwrap().is_equal_to(&expected, &tol));
+        }
+
+        let direction = Vector::x_axis();
+        assert_eq!(
+            curve.project_to_plane(&plane, Some(&direction), &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+    }
 }
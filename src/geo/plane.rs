@@ -65,6 +65,56 @@ impl Plane {
         Vector::new(self.param_a, self.param_b, self.param_c).normal(tol)
     }
 
+    /// Projects a point onto this plane along `direction`.
+    ///
+    /// If `direction` is `None`, the projection is orthogonal.
+    pub fn project_point(
+        &self,
+        point: &Point,
+        direction: Option<&Vector>,
+        tol: &Tolerance
+    ) -> Result<Point, crate::BgcError> {
+        let closest = self.closest_point(point);
+        let Some(direction) = direction else {
+            return Ok(closest);
+        };
+
+        let normal = self.get_normal_vector(tol);
+        let offset = (point - closest).inner_product(&normal);
+
+        Ok(point - *direction * (offset / self.direction_cos(direction, tol)?))
+    }
+
+    /// Projects a vector onto this plane along `direction`.
+    ///
+    /// If `direction` is `None`, the projection is orthogonal.
+    pub fn project_vector(
+        &self,
+        vector: &Vector,
+        direction: Option<&Vector>,
+        tol: &Tolerance
+    ) -> Result<Vector, crate::BgcError> {
+        let normal = self.get_normal_vector(tol);
+        let offset = vector.inner_product(&normal);
+
+        match direction {
+            Some(direction) => Ok(*vector - *direction * (offset / self.direction_cos(direction, tol)?)),
+            None => Ok(*vector - normal * offset),
+        }
+    }
+
+    /// Returns the inner product of the normal vector and `direction`.
+    ///
+    /// A direction parallel to this plane returns `BgcError::InvalidInput`.
+    fn direction_cos(&self, direction: &Vector, tol: &Tolerance) -> Result<f64, crate::BgcError> {
+        let cos = self.get_normal_vector(tol).inner_product(direction);
+        if cos.abs() < tol.equal_vector() * direction.length() {
+            return Err(crate::BgcError::InvalidInput);
+        }
+
+        Ok(cos)
+    }
+
     pub fn is_parallel_to(&self, other: &Plane, tol: &Tolerance) -> bool {
         let self_normal = self.get_normal_vector(tol);
         let other_normal = other.get_normal_vector(tol);
@@ -75,6 +125,31 @@ impl Plane {
         self.is_parallel_to(other, tol) && (self.param_d - other.param_d).abs() < tol.equal_point()
     }
 
+    /// Transforms this plane to the coordinate system of the transformation matrix
+    ///
+    /// \[A' B' C' D'\] = (\[M\]^-1)^T * \[A B C D\]
+    pub fn transform(&self, mat: &Matrix3d, tol: &Tolerance) -> Result<Self, crate::BgcError> {
+        let inv_t = mat.inverse(tol)?.transpose();
+        let params = [self.param_a, self.param_b, self.param_c, self.param_d];
+
+        let mut result = [0.0; 4];
+        for (i, r) in result.iter_mut().enumerate() {
+            *r = (0..4).map(|j| inv_t.get(i, j) * params[j]).sum();
+        }
+
+        let s = (result[0].powi(2) + result[1].powi(2) + result[2].powi(2)).sqrt();
+        if s < tol.equal_vector() {
+            return Err(crate::BgcError::MustBeNonZero);
+        }
+
+        Ok(Self {
+            param_a: result[0] / s,
+            param_b: result[1] / s,
+            param_c: result[2] / s,
+            param_d: result[3] / s,
+        })
+    }
+
     pub fn intersect_with_plane(
         &self,
         other: &Plane,
@@ -297,4 +372,60 @@ mod tests {
         assert!(result2.is_err());
         assert_eq!(result2.unwrap_err(), crate::BgcError::InvalidInput);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn plane_transform() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::new(0.0, 0.0, 2.0), &Vector::new(0.0, 0.0, 1.0), &tol);
+
+        // Rotation and translation
+        let mat = Matrix3d::transform_to_world(
+            &Point::new(5.0, 0.0, 0.0),
+            &Vector::new(0.0, 1.0, 0.0),
+            &Vector::new(0.0, 0.0, 1.0),
+            &tol
+        );
+        let moved = plane.transform(&mat, &tol).unwrap();
+        // The local Z axis is mapped to the world X axis
+        assert!(moved.get_normal_vector(&tol).is_parallel_to(&Vector::x_axis(), &tol));
+        assert!(moved.contains(&Point::new(7.0, 3.0, -1.0), &tol));
+
+        // Non-uniform scaling keeps points on the plane
+        let angled = Plane::from(&Point::new(1.0, 0.0, 0.0), &Vector::new(1.0, 1.0, 0.0), &tol);
+        let mut mat = Matrix3d::identity();
+        mat.set(0, 0, 2.0);
+        mat.set(1, 3, 1.0);
+        let scaled = angled.transform(&mat, &tol).unwrap();
+        for p in [Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 5.0), Point::new(3.0, -2.0, 1.0)] {
+            assert!(angled.contains(&p, &tol));
+            assert!(scaled.contains(&p.transform(&mat, &tol).unwrap(), &tol));
+        }
+        assert!((scaled.get_normal_vector(&tol).length() - 1.0).abs() < tol.calculation());
+
+        // Singular matrix
+        let result = plane.transform(&Matrix3d::new(), &tol);
+        assert_eq!(result.unwrap_err(), crate::BgcError::MustBeNonZero);
+    }
+
+    #[test]
+    fn plane_project_point() {
+        let tol = Tolerance::default();
+        let plane = Plane::from(&Point::new(0.0, 0.0, 1.0), &Vector::z_axis(), &tol);
+        let p = Point::new(2.0, 3.0, 5.0);
+
+        let projected = plane.project_point(&p, None, &tol).unwrap();
+        assert!(projected.is_equal_to(&Point::new(2.0, 3.0, 1.0), &tol));
+
+        let direction = Vector::new(1.0, 0.0, 1.0);
+        let projected = plane.project_point(&p, Some(&direction), &tol).unwrap();
+        assert!(projected.is_equal_to(&Point::new(-2.0, 3.0, 1.0), &tol));
+
+        let v = plane.project_vector(&Vector::new(1.0, 1.0, 1.0), Some(&direction), &tol).unwrap();
+        assert!(v.is_equal_to(&Vector::new(0.0, 1.0, 0.0), &tol));
+
+        assert_eq!(
+            plane.project_point(&p, Some(&Vector::x_axis()), &tol).unwrap_err(),
+            crate::BgcError::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// A half-infinite line starting at `origin`.
+#[derive(Debug, Clone, Copy)]
+pub struct Ray {
+    pub origin: Point,
+    /// Unit vector
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub fn new(origin: Point, direction: &Vector, tol: &Tolerance) -> Result<Self, BgcError> {
+        if direction.length() < tol.equal_vector() {
+            return Err(BgcError::MustBeNonZero);
+        }
+
+        Ok(Self { origin, direction: direction.normal(tol) })
+    }
+
+    /// Makes the ray from the start point of a line segment through its end point.
+    pub fn from_line(line: &Line, tol: &Tolerance) -> Result<Self, BgcError> {
+        if line.length() < tol.equal_point() {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Self::new(line.start_point, &(line.end_point - line.start_point), tol)
+    }
+
+    /// Returns the segment of this ray from the origin to `length`.
+    pub fn to_line(&self, length: f64) -> Line {
+        Line::new(self.origin, self.point_at(length))
+    }
+
+    /// Returns the point at a distance from the origin.
+    pub fn point_at(&self, distance: f64) -> Point {
+        self.origin + self.direction * distance
+    }
+
+    /// Calculates the distance from the origin to the closest point to input point.
+    pub fn param_at_point(&self, point: &Point) -> f64 {
+        (point - self.origin).inner_product(&self.direction).max(0.0)
+    }
+
+    /// Calculates the closest point on this ray to input point.
+    pub fn closest_point(&self, point: &Point) -> Point {
+        self.point_at(self.param_at_point(point))
+    }
+
+    pub fn distance_to_point(&self, point: &Point) -> f64 {
+        self.closest_point(point).distance_to(point)
+    }
+
+    /// Determines if input point lies on this ray.
+    pub fn contains(&self, point: &Point, tol: &Tolerance) -> bool {
+        self.distance_to_point(point) <= tol.equal_point()
+    }
+
+    /// Calculates the intersection point of input ray and this ray.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::MustBeNonZero)` - the rays are parallel
+    /// * `Err(BgcError::InvalidInput)` - the rays do not meet
+    pub fn intersect_with_ray(&self, other: &Self, tol: &Tolerance) -> Result<Point, BgcError> {
+        let p = InfiniteLine::from(self).intersect_with_infinite_line(&InfiniteLine::from(other), tol)?;
+        if !self.contains(&p, tol) || !other.contains(&p, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Ok(p)
+    }
+
+    /// Calculates the intersection points of input curve and this ray, ordered by the
+    /// distance from the origin.
+    pub fn intersect_with<T>(&self, curve: &T, tol: &Tolerance) -> Result<Vec<Point>, BgcError>
+    where
+        T: Curve + ?Sized
+    {
+        Ok(InfiniteLine::from(self)
+            .intersect_with(curve, tol)?
+            .into_iter()
+            .filter(|p| self.contains(p, tol))
+            .collect())
+    }
+
+    /// Calculates the intersection point of input plane and this ray.
+    ///
+    /// A ray parallel to the plane or pointing away from it returns
+    /// `BgcError::InvalidInput`.
+    pub fn intersect_with_plane(&self, plane: &Plane, tol: &Tolerance) -> Result<Point, BgcError> {
+        let p = InfiniteLine::from(self).intersect_with_plane(plane, tol)?;
+        if !self.contains(&p, tol) {
+            return Err(BgcError::InvalidInput);
+        }
+
+        Ok(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_closest_point() {
+        let tol = Tolerance::default();
+        let ray = Ray::new(Point::new(1.0, 1.0, 0.0), &Vector::new(0.0, 3.0, 0.0), &tol).unwrap();
+
+        assert!(ray.closest_point(&Point::new(2.0, 5.0, 0.0)).is_equal_to(&Point::new(1.0, 5.0, 0.0), &tol));
+
+        // Behind the origin
+        let p = Point::new(4.0, -3.0, 0.0);
+        assert!(ray.closest_point(&p).is_equal_to(&ray.origin, &tol));
+        assert!((ray.distance_to_point(&p) - 5.0).abs() < tol.calculation());
+        assert!(!ray.contains(&Point::new(1.0, -1.0, 0.0), &tol));
+    }
+
+    #[test]
+    fn ray_conversions() {
+        let tol = Tolerance::default();
+        let segment = Line::new(Point::new(1.0, 0.0, 0.0), Point::new(4.0, 4.0, 0.0));
+
+        let ray = Ray::from_line(&segment, &tol).unwrap();
+        assert!(ray.direction.is_equal_to(&Vector::new(0.6, 0.8, 0.0), &tol));
+
+        let back = ray.to_line(10.0);
+        assert!(back.start_point.is_equal_to(&segment.start_point, &tol));
+        assert!(back.end_point.is_equal_to(&Point::new(7.0, 8.0, 0.0), &tol));
+
+        let line = InfiniteLine::from(&ray);
+        assert!(line.contains(&Point::new(-2.0, -4.0, 0.0), &tol));
+        assert!(!ray.contains(&Point::new(-2.0, -4.0, 0.0), &tol));
+    }
+
+    #[test]
+    fn ray_intersections() {
+        let tol = Tolerance::default();
+        let ray = Ray::new(Point::origin(), &Vector::x_axis(), &tol).unwrap();
+
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI * 2.0,
+        };
+        let points = ray.intersect_with(&arc, &tol).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!(points[0].is_equal_to(&Point::new(2.0, 0.0, 0.0), &tol));
+
+        let shifted = Ray::new(Point::new(-5.0, 0.0, 0.0), &Vector::x_axis(), &tol).unwrap();
+        let points = shifted.intersect_with(&arc, &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points[0].is_equal_to(&Point::new(-2.0, 0.0, 0.0), &tol));
+
+        let behind = Line::new(Point::new(-3.0, -1.0, 0.0), Point::new(-3.0, 1.0, 0.0));
+        assert!(ray.intersect_with(&behind, &tol).unwrap().is_empty());
+
+        let other = Ray::new(Point::new(3.0, -3.0, 0.0), &Vector::y_axis(), &tol).unwrap();
+        let p = ray.intersect_with_ray(&other, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(3.0, 0.0, 0.0), &tol));
+        let away = Ray::new(Point::new(3.0, -3.0, 0.0), &(Vector::y_axis() * -1.0), &tol).unwrap();
+        assert_eq!(ray.intersect_with_ray(&away, &tol).unwrap_err(), BgcError::InvalidInput);
+
+        let plane = Plane::from(&Point::new(5.0, 0.0, 0.0), &Vector::x_axis(), &tol);
+        let p = ray.intersect_with_plane(&plane, &tol).unwrap();
+        assert!(p.is_equal_to(&Point::new(5.0, 0.0, 0.0), &tol));
+        let plane = Plane::from(&Point::new(-5.0, 0.0, 0.0), &Vector::x_axis(), &tol);
+        assert_eq!(ray.intersect_with_plane(&plane, &tol).unwrap_err(), BgcError::InvalidInput);
+    }
+}
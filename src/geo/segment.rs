@@ -0,0 +1,139 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+/// A line or an arc in a profile.
+#[derive(Debug)]
+pub enum Segment {
+    Line(Line),
+    Arc(Arc),
+}
+
+impl Segment {
+    pub fn as_curve(&self) -> &dyn Curve {
+        match self {
+            Segment::Line(line) => line,
+            Segment::Arc(arc) => arc,
+        }
+    }
+
+    pub fn start_point(&self) -> Point {
+        match self {
+            Segment::Line(line) => line.start_point,
+            Segment::Arc(arc) => arc.start_point(),
+        }
+    }
+
+    pub fn end_point(&self) -> Point {
+        match self {
+            Segment::Line(line) => line.end_point,
+            Segment::Arc(arc) => arc.end_point(),
+        }
+    }
+
+    /// Determines if this segment lies on the plane.
+    pub fn lies_on(&self, plane: &Plane, tol: &Tolerance) -> bool {
+        match self {
+            Segment::Line(line) => {
+                plane.contains(&line.start_point, tol) && plane.contains(&line.end_point, tol)
+            },
+            Segment::Arc(arc) => {
+                let normal = arc.x_axis.outer_product(&arc.y_axis);
+                plane.contains(&arc.center_point, tol)
+                    && normal.is_parallel_to(&plane.get_normal_vector(tol), tol)
+            },
+        }
+    }
+
+    /// Calculates the closest point to input point on the extension of this segment.
+    pub fn closest_point_on_extension(&self, point: &Point, tol: &Tolerance) -> Result<Point, BgcError> {
+        match self {
+            Segment::Line(line) => Ok(line.closest_point(point, true, tol)),
+            Segment::Arc(arc) => arc.closest_point(point, true, tol),
+        }
+    }
+
+    /// Moves the given end of this segment to the projection of input point onto its
+    /// extension, trimming or extending it.
+    ///
+    /// An arc end moves forward along the circle from the start, or backward from the end.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BgcError::InvalidInput)` - nothing of the segment would remain
+    pub fn move_end_to(&self, end: CurveEnd, point: &Point, tol: &Tolerance) -> Result<Self, BgcError> {
+        match self {
+            Segment::Line(line) => {
+                let target = line.closest_point(point, true, tol);
+                let moved = match end {
+                    CurveEnd::Start => Line::new(target, line.end_point),
+                    CurveEnd::End => Line::new(line.start_point, target),
+                };
+                if moved.length() < tol.equal_point()
+                        || moved.direction(tol).inner_product(&line.direction(tol)) < 0.0 {
+                    return Err(BgcError::InvalidInput);
+                }
+
+                Ok(Segment::Line(moved))
+            },
+            Segment::Arc(arc) => {
+                let arc = arc.to_counterclockwise();
+                let full = std::f64::consts::PI * 2.0;
+                let angle = arc.param_at_point(point, true, tol)?;
+
+                let (start_angle, end_angle) = match end {
+                    CurveEnd::Start => (arc.end_angle - (arc.end_angle - angle).rem_euclid(full), arc.end_angle),
+                    CurveEnd::End => (arc.start_angle, arc.start_angle + (angle - arc.start_angle).rem_euclid(full)),
+                };
+                if (end_angle - start_angle) * arc.radius < tol.equal_point() {
+                    return Err(BgcError::InvalidInput);
+                }
+
+                Ok(Segment::Arc(Arc { start_angle, end_angle, ..arc }))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_move_end_to() {
+        let tol = Tolerance::default();
+
+        let line = Segment::Line(Line::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0)));
+        let moved = line.move_end_to(CurveEnd::End, &Point::new(6.0, 1.0, 0.0), &tol).unwrap();
+        assert!(moved.end_point().is_equal_to(&Point::new(6.0, 0.0, 0.0), &tol));
+        let moved = line.move_end_to(CurveEnd::Start, &Point::new(1.0, 1.0, 0.0), &tol).unwrap();
+        assert!(moved.start_point().is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+        assert_eq!(
+            line.move_end_to(CurveEnd::End, &Point::new(-1.0, 0.0, 0.0), &tol).unwrap_err(),
+            BgcError::InvalidInput
+        );
+
+        // A clockwise arc keeps its direction of travel
+        let arc = Segment::Arc(Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 1.0,
+            start_angle: std::f64::consts::FRAC_PI_2,
+            end_angle: 0.0,
+        });
+        let moved = arc.move_end_to(CurveEnd::End, &Point::new(0.0, -2.0, 0.0), &tol).unwrap();
+        assert!(moved.start_point().is_equal_to(&Point::new(0.0, 1.0, 0.0), &tol));
+        assert!(moved.end_point().is_equal_to(&Point::new(0.0, -1.0, 0.0), &tol));
+        let Segment::Arc(moved) = moved else {
+            panic!("the segment should stay an arc");
+        };
+        assert!((moved.length() - std::f64::consts::PI).abs() < tol.calculation());
+
+        let moved = arc.move_end_to(CurveEnd::Start, &Point::new(1.0, 1.0, 0.0), &tol).unwrap();
+        assert!(moved.start_point().is_equal_to(
+            &Point::new(std::f64::consts::FRAC_1_SQRT_2, std::f64::consts::FRAC_1_SQRT_2, 0.0),
+            &tol
+        ));
+        assert!(moved.end_point().is_equal_to(&Point::new(1.0, 0.0, 0.0), &tol));
+    }
+}
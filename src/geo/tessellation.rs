@@ -0,0 +1,256 @@
+use super::*;
+use crate::{ BgcError, Tolerance };
+
+const DEFAULT_MAX_DEVIATION: f64 = 1.0e-2;
+const DEFAULT_MAX_ANGLE: f64 = std::f64::consts::PI / 18.0;
+const DEFAULT_MAX_LENGTH: f64 = 0.0;
+
+/// Subdivision stops at this depth even if the limits are not satisfied.
+const MAX_DEPTH: usize = 24;
+
+/// Limits of the polyline made by `Curve::tessellate`.
+///
+/// A limit of 0.0 is not used.
+#[derive(Debug, Clone)]
+pub struct TessellationOptions {
+    max_deviation: f64,
+    max_angle: f64,
+    max_length: f64,
+}
+
+impl Default for TessellationOptions {
+    fn default() -> Self {
+        Self { max_deviation: DEFAULT_MAX_DEVIATION,
+               max_angle: DEFAULT_MAX_ANGLE,
+               max_length: DEFAULT_MAX_LENGTH }
+    }
+}
+
+impl TessellationOptions {
+    pub fn new(max_deviation: f64, max_angle: f64, max_length: f64) -> Self {
+        let mut options = Self::default();
+        options.set_max_deviation(max_deviation);
+        options.set_max_angle(max_angle);
+        options.set_max_length(max_length);
+
+        options
+    }
+
+    /// Maximum distance between a segment and the curve.
+    pub fn max_deviation(&self) -> f64 {
+        self.max_deviation
+    }
+
+    /// Maximum angle in radians between the tangents at both ends of a segment.
+    pub fn max_angle(&self) -> f64 {
+        self.max_angle
+    }
+
+    /// Maximum length of a segment.
+    pub fn max_length(&self) -> f64 {
+        self.max_length
+    }
+
+    pub fn set_max_deviation(&mut self, value: f64) {
+        self.max_deviation = if value < 0.0 {
+            DEFAULT_MAX_DEVIATION
+        } else {
+            value
+        };
+    }
+
+    pub fn set_max_angle(&mut self, value: f64) {
+        self.max_angle = if value < 0.0 {
+            DEFAULT_MAX_ANGLE
+        } else {
+            value
+        };
+    }
+
+    pub fn set_max_length(&mut self, value: f64) {
+        self.max_length = if value < 0.0 {
+            DEFAULT_MAX_LENGTH
+        } else {
+            value
+        };
+    }
+}
+
+/// Approximates a curve by a polyline.
+///
+/// Each span between breakpoints is bisected recursively until every segment satisfies
+/// the limits of `options`.
+pub(crate) fn tessellate<C: Curve + ?Sized>(
+    curve: &C,
+    options: &TessellationOptions,
+    tol: &Tolerance
+) -> Result<Vec<(f64, Point)>, BgcError> {
+    let breaks = curve.breakpoints();
+    if breaks.is_empty() {
+        return Err(BgcError::Empty);
+    }
+
+    let mut points = vec![(breaks[0], curve.point_at_param(breaks[0], false, tol)?)];
+    for span in breaks.windows(2) {
+        let start = points[points.len() - 1];
+        let end = (span[1], curve.point_at_param(span[1], false, tol)?);
+        subdivide(curve, start, end, 0, options, tol, &mut points)?;
+    }
+
+    Ok(points)
+}
+
+/// Pushes the vertices after `start` up to `end`.
+fn subdivide<C: Curve + ?Sized>(
+    curve: &C,
+    start: (f64, Point),
+    end: (f64, Point),
+    depth: usize,
+    options: &TessellationOptions,
+    tol: &Tolerance,
+    points: &mut Vec<(f64, Point)>
+) -> Result<(), BgcError> {
+    if depth >= MAX_DEPTH || is_within_limits(curve, start, end, options, tol)? {
+        points.push(end);
+        return Ok(());
+    }
+
+    let mid_param = (start.0 + end.0) / 2.0;
+    let mid = (mid_param, curve.point_at_param(mid_param, false, tol)?);
+
+    subdivide(curve, start, mid, depth + 1, options, tol, points)?;
+    subdivide(curve, mid, end, depth + 1, options, tol, points)
+}
+
+fn is_within_limits<C: Curve + ?Sized>(
+    curve: &C,
+    start: (f64, Point),
+    end: (f64, Point),
+    options: &TessellationOptions,
+    tol: &Tolerance
+) -> Result<bool, BgcError> {
+    let chord = Line::new(start.1, end.1);
+
+    if options.max_length() > 0.0 && chord.length() > options.max_length() {
+        return Ok(false);
+    }
+
+    if options.max_deviation() > 0.0 {
+        // Checking the quarter points as well detects S-shaped pieces
+        for ratio in [0.25, 0.5, 0.75] {
+            let p = curve.point_at_param(start.0 + (end.0 - start.0) * ratio, false, tol)?;
+            if chord.closest_point(&p, false, tol).distance_to(&p) > options.max_deviation() {
+                return Ok(false);
+            }
+        }
+    }
+
+    if options.max_angle() > 0.0 {
+        let (start_tangent, _) = curve.derivatives_at_param(start.0, false, tol)?;
+        let (end_tangent, _) = curve.derivatives_at_param(end.0, false, tol)?;
+        if start_tangent.angle_to(&end_tangent, tol) > options.max_angle() {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn max_chord_deviation<C: Curve>(curve: &C, points: &[(f64, Point)], tol: &Tolerance) -> f64 {
+        let mut max = 0.0_f64;
+        for pair in points.windows(2) {
+            let chord = Line::new(pair[0].1, pair[1].1);
+            for i in 1..10 {
+                let t = pair[0].0 + (pair[1].0 - pair[0].0) * i as f64 / 10.0;
+                let p = curve.point_at_param(t, false, tol).unwrap();
+                max = max.max(chord.closest_point(&p, false, tol).distance_to(&p));
+            }
+        }
+        max
+    }
+
+    #[test]
+    fn tessellation_options() {
+        let options = TessellationOptions::new(0.5, -1.0, 2.0);
+        assert_eq!(options.max_deviation(), 0.5);
+        assert_eq!(options.max_angle(), DEFAULT_MAX_ANGLE);
+        assert_eq!(options.max_length(), 2.0);
+    }
+
+    #[test]
+    fn tessellate_line() {
+        let tol = Tolerance::default();
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0));
+
+        let points = line.tessellate(&TessellationOptions::default(), &tol).unwrap();
+        assert_eq!(points.len(), 2);
+        assert!(points[0].1.is_equal_to(&line.start_point, &tol));
+        assert!(points[1].1.is_equal_to(&line.end_point, &tol));
+
+        let points = line.tessellate(&TessellationOptions::new(0.01, 0.1, 3.0), &tol).unwrap();
+        assert_eq!(points.len(), 5);
+        for pair in points.windows(2) {
+            assert!(pair[0].1.distance_to(&pair[1].1) <= 3.0);
+            assert!(pair[0].0 < pair[1].0);
+        }
+    }
+
+    #[test]
+    fn tessellate_arc() {
+        let tol = Tolerance::default();
+        let arc = Arc {
+            center_point: Point::origin(),
+            x_axis: Vector::x_axis(),
+            y_axis: Vector::y_axis(),
+            radius: 10.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI * 1.5,
+        };
+
+        let options = TessellationOptions::new(0.01, 0.0, 0.0);
+        let points = arc.tessellate(&options, &tol).unwrap();
+        assert!(points.first().unwrap().1.is_equal_to(&arc.start_point(), &tol));
+        assert!(points.last().unwrap().1.is_equal_to(&arc.end_point(), &tol));
+        assert!(max_chord_deviation(&arc, &points, &tol) <= 0.01);
+        for (t, p) in &points {
+            assert!(arc.point_at_param(*t, false, &tol).unwrap().is_equal_to(p, &tol));
+        }
+
+        let options = TessellationOptions::new(0.0, 0.2, 0.0);
+        let points = arc.tessellate(&options, &tol).unwrap();
+        for pair in points.windows(2) {
+            assert!(pair[1].0 - pair[0].0 <= 0.2 + tol.calculation());
+        }
+    }
+
+    #[test]
+    fn tessellate_nurbs() {
+        let tol = Tolerance::default();
+        let curve = NurbsCurve::new(
+            3,
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 3.0, 0.0),
+                Point::new(3.0, -3.0, 0.0),
+                Point::new(4.0, 0.0, 0.0),
+                Point::new(6.0, 2.0, 1.0),
+            ],
+            vec![1.0, 1.0, 2.0, 1.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0],
+            &tol
+        ).unwrap();
+
+        let options = TessellationOptions::new(0.001, 0.0, 0.0);
+        let points = curve.tessellate(&options, &tol).unwrap();
+
+        assert!(points.iter().any(|(t, _)| (t - 0.5).abs() < tol.calculation()));
+        assert!(max_chord_deviation(&curve, &points, &tol) <= 0.001);
+        for pair in points.windows(2) {
+            assert!(pair[0].0 < pair[1].0);
+        }
+    }
+}
@@ -74,6 +74,26 @@ impl Vector {
         )
     }
 
+    /// Calculates the angle between two vectors.
+    ///
+    /// # Returns
+    /// The angle in radians, in the range `[0.0, PI]`.
+    pub fn angle_to(&self, rhs: &Self, tol: &Tolerance) -> f64 {
+        let ip = self.normal(tol).inner_product(&rhs.normal(tol));
+        ip.clamp(-1.0, 1.0).acos()
+    }
+
+    /// Transforms this vector by the transformation matrix.
+    ///
+    /// Only the rotation and scaling part of the matrix is applied.
+    pub fn transform(&self, mat: &Matrix3d) -> Self {
+        Self::new(
+            mat.get(0, 0) * self.x + mat.get(0, 1) * self.y + mat.get(0, 2) * self.z,
+            mat.get(1, 0) * self.x + mat.get(1, 1) * self.y + mat.get(1, 2) * self.z,
+            mat.get(2, 0) * self.x + mat.get(2, 1) * self.y + mat.get(2, 2) * self.z
+        )
+    }
+
     /// Calculates the angle of XY relative to the positive X-axis.
     /// 
     /// # Returns
@@ -455,4 +475,33 @@ mod tests {
         let n_tiny = v_tiny.normal(&tol);
         assert!(n_tiny.is_equal_to(&Vector::new(0.0, 0.0, 0.0), &tol));
     }
+
+    #[test]
+    fn vector_angle_to() {
+        let tol = Tolerance::default();
+
+        let v = Vector::new(2.0, 0.0, 0.0);
+        let angle = v.angle_to(&Vector::new(0.0, 3.0, 0.0), &tol);
+        assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        let angle = v.angle_to(&Vector::new(1.0, 1.0, 0.0), &tol);
+        assert!((angle - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        let angle = v.angle_to(&Vector::new(-1.0, 0.0, 0.0), &tol);
+        assert!((angle - std::f64::consts::PI).abs() < 1e-12);
+        assert!(v.angle_to(&v, &tol).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vector_transform() {
+        let tol = Tolerance::default();
+        let mat = Matrix3d::transform_to_world(
+            &Point::new(100.0, 200.0, 300.0),
+            &Vector::new(0.0, 1.0, 0.0),
+            &Vector::new(-1.0, 0.0, 0.0),
+            &tol
+        );
+
+        // Translation is not applied to vectors
+        let v = Vector::new(1.0, 2.0, 3.0).transform(&mat);
+        assert!(v.is_equal_to(&Vector::new(-2.0, 1.0, 3.0), &tol));
+    }
 }
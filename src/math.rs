@@ -29,7 +29,6 @@ pub fn quadratic_equation(a: f64, b: f64, c: f64, tol: &Tolerance) -> Result<(f6
     }
 
     if discriminant < 0.0 {
-        dbg!(discriminant);
         return Err(BgcError::MustBeNoNegative);
     }
 
@@ -101,6 +100,67 @@ where
     Ok((pn, val))
 }
 
+/// Finds the minimum of a unimodal function in [low, high] by golden section search.
+///
+/// # Arguments
+///
+/// * `low` - The lower bound of the interval.
+/// * `high` - The upper bound of the interval.
+/// * `func` - The function to minimize: $f(x)$.
+/// * `tol` - The tolerance configuration.
+///
+/// # Returns
+///
+/// * `Ok((result, func_value))` - The found minimum and the function value at it.
+/// * `Err(BgcError::InvalidInput)` - If `high < low`.
+/// * Any error returned by `func`.
+pub fn golden_section_search<F>(
+    low: f64,
+    high: f64,
+    mut func: F,
+    tol: &Tolerance,
+) -> Result<(f64, f64), BgcError>
+where
+    F: FnMut(f64) -> Result<f64, BgcError>,
+{
+    const MAX_ITERATIONS: usize = 200;
+
+    if high < low {
+        return Err(BgcError::InvalidInput);
+    }
+
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let mut low = low;
+    let mut high = high;
+    let mut x1 = high - ratio * (high - low);
+    let mut x2 = low + ratio * (high - low);
+    let mut f1 = func(x1)?;
+    let mut f2 = func(x2)?;
+
+    for _ in 0..MAX_ITERATIONS {
+        if high - low <= tol.convergence() {
+            break;
+        }
+
+        if f1 < f2 {
+            high = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = high - ratio * (high - low);
+            f1 = func(x1)?;
+        } else {
+            low = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = low + ratio * (high - low);
+            f2 = func(x2)?;
+        }
+    }
+
+    let x = (low + high) / 2.0;
+    Ok((x, func(x)?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,4 +245,23 @@ mod tests {
         let r_err3 = newton(0.0, 100, func, dfunc, &tol);
         assert_eq!(r_err3.unwrap_err(), BgcError::MustBeNonZero);
     }
+
+    #[test]
+    fn test_golden_section_search() {
+        let tol = Tolerance::default();
+
+        let r = golden_section_search(0.0, 3.0, |x| Ok((x - 1.2).powi(2) + 0.5), &tol);
+        let Ok((x, val)) = r else {
+            panic!("golden_section_search failed: {:?}", r.unwrap_err());
+        };
+        assert!((x - 1.2).abs() <= tol.convergence());
+        assert!((val - 0.5).abs() <= tol.convergence());
+
+        // The minimum on the boundary
+        let (x, _) = golden_section_search(2.0, 3.0, |x| Ok((x - 1.2).powi(2)), &tol).unwrap();
+        assert!((x - 2.0).abs() <= tol.convergence());
+
+        let r = golden_section_search(3.0, 0.0, Ok, &tol);
+        assert_eq!(r.unwrap_err(), BgcError::InvalidInput);
+    }
 }